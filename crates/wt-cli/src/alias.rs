@@ -0,0 +1,113 @@
+//! User-defined command aliases, resolved before clap ever sees `argv`.
+//!
+//! Mirrors cargo's `[alias]` mechanism: a `wt.toml` entry maps a short name
+//! to an argument vector that gets spliced in place of the first positional
+//! token, then re-dispatched. Expansion happens here, ahead of
+//! [`crate::cli::Cli::parse`], so built-in subcommands never see an alias.
+
+use std::collections::{HashMap, HashSet};
+
+use wt_core::AliasValue;
+
+/// Every built-in subcommand name and its `visible_alias`es, kept in sync
+/// with `Commands` in `cli.rs`. An alias matching one of these is rejected
+/// at load time rather than silently shadowing it.
+const BUILTIN_COMMANDS: &[&str] = &[
+    "create", "c", "list", "ls", "open", "o", "remove", "rm", "exit", "unstash", "reset",
+    "config", "spawn", "ps", "tag", "untag", "completions", "attach", "switch", "logs", "review",
+    "merge", "kill", "init", "update", "sync", "version", "which", "health", "tui", "status",
+    "lock", "unlock", "move", "undo", "oplog", "prune", "repair", "help", "projects", "workon",
+];
+
+/// Maximum number of alias expansions before giving up. Generous enough for
+/// any legitimate chain (aliases referencing aliases), tight enough to catch
+/// a cycle fast.
+const MAX_EXPANSIONS: usize = 8;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AliasError {
+    #[error("alias `{0}` shadows a built-in command and was ignored")]
+    ShadowsBuiltin(String),
+    #[error("alias `{0}` is cyclic")]
+    Cyclic(String),
+    #[error("alias expansion for `{0}` exceeded the depth limit ({MAX_EXPANSIONS}); check for a cycle")]
+    TooDeep(String),
+}
+
+/// Drop any configured alias whose name collides with a built-in command
+/// (or one of its `visible_alias`es), returning the surviving aliases plus
+/// a description of anything dropped so the caller can warn.
+pub fn validate(aliases: HashMap<String, AliasValue>) -> (HashMap<String, AliasValue>, Vec<AliasError>) {
+    let mut kept = HashMap::new();
+    let mut errors = Vec::new();
+    for (name, value) in aliases {
+        if BUILTIN_COMMANDS.contains(&name.as_str()) {
+            errors.push(AliasError::ShadowsBuiltin(name));
+            continue;
+        }
+        kept.insert(name, value);
+    }
+    (kept, errors)
+}
+
+/// Expand `args` (a full `argv`, `args[0]` being the binary name) by
+/// repeatedly substituting its first positional token for a matching alias,
+/// until the token is a built-in command, isn't an alias, or the expansion
+/// depth limit is hit (which catches `a = "b"` / `b = "a"` cycles).
+pub fn expand(mut args: Vec<String>, aliases: &HashMap<String, AliasValue>) -> Result<Vec<String>, AliasError> {
+    let mut seen = HashSet::new();
+
+    for _ in 0..MAX_EXPANSIONS {
+        let Some(token) = args.get(1).cloned() else {
+            return Ok(args);
+        };
+        if BUILTIN_COMMANDS.contains(&token.as_str()) {
+            return Ok(args);
+        }
+        let Some(alias) = aliases.get(&token) else {
+            return Ok(args);
+        };
+        if !seen.insert(token.clone()) {
+            return Err(AliasError::Cyclic(token));
+        }
+
+        let mut expanded = vec![args[0].clone()];
+        expanded.extend(alias.clone().into_args());
+        expanded.extend(args.into_iter().skip(2));
+        args = expanded;
+    }
+
+    Err(AliasError::TooDeep(args[1].clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    /// `BUILTIN_COMMANDS` is maintained by hand (see its doc comment), so
+    /// nothing enforces it actually covers every `Commands` variant — this
+    /// caught chunk4-3/chunk5-4/chunk5-6/chunk6-6/chunk6-7 all missing their
+    /// new subcommand from the list. Walk clap's own view of the CLI instead
+    /// of a second hand-written list of names, so a future subcommand that's
+    /// forgotten here fails this test rather than silently letting an alias
+    /// shadow it.
+    #[test]
+    fn builtin_commands_covers_every_subcommand() {
+        let command = crate::cli::Cli::command();
+        for sub in command.get_subcommands() {
+            assert!(
+                BUILTIN_COMMANDS.contains(&sub.get_name()),
+                "Commands::{} missing from BUILTIN_COMMANDS",
+                sub.get_name()
+            );
+            for alias in sub.get_visible_aliases() {
+                assert!(
+                    BUILTIN_COMMANDS.contains(&alias),
+                    "visible_alias `{alias}` of {} missing from BUILTIN_COMMANDS",
+                    sub.get_name()
+                );
+            }
+        }
+    }
+}
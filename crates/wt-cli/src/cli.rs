@@ -1,11 +1,28 @@
+//! Command-line argument definitions.
+//!
+//! `argv` passes through [`crate::alias`] first, so by the time [`Cli::parse`]
+//! runs here, any `[alias]` entry in `wt.toml` has already been expanded into
+//! its target arguments.
+
 use clap::{Parser, Subcommand};
+use wt_core::Error;
 
 #[derive(Parser, Debug)]
 #[command(
     name = "wt",
     about = "Git worktree manager for parallel Claude Code sessions",
     version,
-    after_help = "Use 'wt <command> --help' for more information about a command."
+    after_help = "Use 'wt <command> --help' for more information about a command.\n\n\
+Exit codes:\n  \
+0  success\n  \
+1  generic/unclassified error\n  \
+2  not found (not in a git repo/worktree, or the named thing doesn't exist)\n  \
+3  refused: worktree has uncommitted changes or unmerged commits\n  \
+4  unavailable (permission denied, missing dependency, or a conflicting name already exists)\n  \
+5  bad usage (a required argument was missing or invalid)\n\n\
+Pass --json-errors to get a failure as a single-line JSON object on stderr\n\
+({ \"code\": ..., \"message\": ..., \"details\": ... }) instead of prose, for\n\
+scripts that want to branch on `code` rather than grep the message."
 )]
 pub struct Cli {
     /// Open/cd into worktree after creating
@@ -16,6 +33,19 @@ pub struct Cli {
     #[arg(long = "no-hooks", global = true)]
     pub no_hooks: bool,
 
+    /// On failure, print a JSON object ({ "code", "message", "details" })
+    /// to stderr instead of the human-readable message, for scripts that
+    /// want to branch on `code` rather than grep prose. Distinct from
+    /// `wt health --json`, which reports health-check results, not errors.
+    #[arg(long = "json-errors", global = true)]
+    pub json_errors: bool,
+
+    /// Override a config key for this invocation only, e.g. `--config
+    /// repo.base_branch=develop`. Repeatable; wins over every other config
+    /// layer (wt.toml, the global config, and `WT_*` env vars).
+    #[arg(long = "config", global = true, value_name = "KEY=VALUE")]
+    pub config: Vec<String>,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -60,6 +90,11 @@ pub enum Commands {
         /// Force removal even with uncommitted changes
         #[arg(long, short)]
         force: bool,
+
+        /// Stash uncommitted changes instead of failing; recover them later
+        /// with `wt unstash`
+        #[arg(long)]
+        stash: bool,
     },
 
     /// Exit current worktree and remove it
@@ -67,6 +102,32 @@ pub enum Commands {
         /// Force removal even with uncommitted changes
         #[arg(long, short)]
         force: bool,
+
+        /// Stash uncommitted changes instead of failing; recover them later
+        /// with `wt unstash`
+        #[arg(long)]
+        stash: bool,
+    },
+
+    /// Re-apply a stash saved by `wt remove --stash`/`wt exit --stash`,
+    /// recreating the worktree first if it's gone
+    Unstash {
+        /// Worktree name the stash was tagged with
+        name: String,
+    },
+
+    /// Discard a worktree's uncommitted changes back to its branch HEAD
+    Reset {
+        /// Worktree name
+        name: String,
+
+        /// Unstage changes without touching the working tree
+        #[arg(long)]
+        staged_only: bool,
+
+        /// Skip the uncommitted-changes guard
+        #[arg(long, short)]
+        force: bool,
     },
 
     /// Manage configuration
@@ -79,7 +140,7 @@ pub enum Commands {
         list: bool,
     },
 
-    /// Create worktree and launch Claude in tmux
+    /// Create worktree and launch an agent in tmux
     Spawn {
         /// Worktree name
         name: String,
@@ -88,20 +149,105 @@ pub enum Commands {
         #[arg(long, short)]
         context: Option<String>,
 
-        /// Auto-start Claude with full prompt
+        /// Auto-start the agent with full prompt
         #[arg(long)]
         auto: bool,
+
+        /// Adapter to launch (defaults to wt.toml's spawn.default_adapter, then "claude")
+        #[arg(long)]
+        adapter: Option<String>,
+
+        /// Also spawn in wt.toml [projects] matching this glob pattern
+        /// (e.g. "*" for all, "api-*" for a subset), cloning them on first use
+        #[arg(long)]
+        repos: Option<String>,
+
+        /// Exclude [projects] matching this glob pattern from --repos
+        #[arg(long)]
+        exclude_repos: Option<String>,
+
+        /// Reuse the name even if a live task/window already has it,
+        /// killing the old window first
+        #[arg(long, short)]
+        force: bool,
     },
 
     /// Show status of spawned sessions
-    Ps,
+    Ps {
+        /// Print only matching worktree/window names, one per line, instead
+        /// of the full table — for scripting and shell completion
+        #[arg(short = 'q', long)]
+        quiet: bool,
+
+        /// With --quiet, only list names starting with this prefix
+        prefix: Option<String>,
+
+        /// Only show workers carrying this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
+
+    /// Add a tag to a worker, for bulk --tag operations
+    Tag {
+        /// Worker name
+        name: String,
+
+        /// Tag to add
+        tag: String,
+    },
+
+    /// Remove a tag from a worker
+    Untag {
+        /// Worker name
+        name: String,
+
+        /// Tag to remove
+        tag: String,
+    },
+
+    /// Print a shell completion script with dynamic worker-name completion
+    Completions {
+        /// Shell to generate a script for (bash, zsh, fish)
+        shell: String,
+    },
 
     /// Attach to tmux session
     Attach {
-        /// Window name to switch to
+        /// Window name to switch to, or "-" to toggle back to the previously
+        /// attached window
+        name: Option<String>,
+
+        /// Attach read-only, without being able to drive the pane
+        #[arg(short = 'r', long = "read-only")]
+        read_only: bool,
+
+        /// Detach any other client already attached to the session
+        #[arg(short = 'd', long = "detach-others")]
+        detach_others: bool,
+    },
+
+    /// Select a worker's window without attaching a new client - for
+    /// bouncing between workers from inside an already-attached terminal
+    Switch {
+        /// Window name to switch to, or "-"/omitted to toggle back to the
+        /// previously-focused window
         name: Option<String>,
     },
 
+    /// Tail a worker's tmux pane output without attaching
+    Logs {
+        /// Worktree name
+        name: String,
+
+        /// Scrollback lines to include in addition to the visible pane
+        #[arg(long, short, default_value_t = 200)]
+        lines: usize,
+
+        /// Keep polling for new output instead of printing once and exiting
+        #[arg(long, short)]
+        follow: bool,
+    },
+
     /// Show diff for parent review
     Review {
         /// Worktree name
@@ -116,6 +262,19 @@ pub enum Commands {
     Merge {
         /// Worktree name
         name: String,
+
+        /// Refuse to merge unless it can be fast-forwarded
+        #[arg(long)]
+        ff_only: bool,
+
+        /// Always create a merge commit, even if a fast-forward is possible
+        #[arg(long)]
+        no_ff: bool,
+
+        /// Rebase the worker branch onto the base branch instead of merging,
+        /// for a linear history. Conflicts with --no-ff.
+        #[arg(long, conflicts_with = "no_ff")]
+        rebase: bool,
     },
 
     /// Kill a running tmux window
@@ -137,6 +296,16 @@ pub enum Commands {
         /// Run Claude audit to populate docs
         #[arg(long)]
         audit: bool,
+
+        /// Template variable for this invocation, e.g. `--var license=MIT`.
+        /// Repeatable; wins over `[init.vars]` in wt.toml.
+        #[arg(long = "var", value_name = "KEY=VALUE")]
+        vars: Vec<String>,
+
+        /// Tag for the global project registry, e.g. `--tag backend`.
+        /// Repeatable; filterable later with `wt projects --tag`.
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
     },
 
     /// Update wt to latest version
@@ -146,6 +315,26 @@ pub enum Commands {
         force: bool,
     },
 
+    /// Rebase worktrees that set `follow` in their .wt/config.toml onto it
+    Sync {
+        /// Specific worktree name(s) to sync (defaults to every worktree
+        /// with a `follow` ref configured)
+        names: Vec<String>,
+    },
+
+    /// List every repo registered via `wt init`, across all of them
+    Projects {
+        /// Only show projects carrying this tag
+        #[arg(long)]
+        tag: Option<String>,
+    },
+
+    /// Print a `cd` line to jump into a registered project by name
+    Workon {
+        /// Project name, as shown by `wt projects`
+        name: String,
+    },
+
     /// Show version information
     Version,
 
@@ -153,7 +342,13 @@ pub enum Commands {
     Which,
 
     /// Show terminal and dependency status
-    Health,
+    Health {
+        /// Emit a machine-readable JSON report instead of the human-formatted
+        /// checklist, and exit non-zero if a required dependency or file is
+        /// missing — for pre-commit hooks and CI gates.
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Launch the terminal UI
     Tui,
@@ -163,6 +358,54 @@ pub enum Commands {
         /// Worker name
         name: Option<String>,
     },
+
+    /// Lock a worktree against removal (e.g. on removable media)
+    Lock {
+        /// Worktree name
+        name: String,
+
+        /// Reason for the lock, shown by `git worktree list`
+        reason: Option<String>,
+    },
+
+    /// Unlock a previously locked worktree
+    Unlock {
+        /// Worktree name
+        name: String,
+    },
+
+    /// Move a worktree to a new location
+    #[command(name = "move")]
+    Move {
+        /// Current worktree name
+        name: String,
+
+        /// New path, relative to the worktrees directory
+        to: String,
+    },
+
+    /// Undo the last recorded state mutation (spawn, kill, or remove), or a
+    /// specific one by id from `wt oplog`
+    Undo {
+        /// Op-log entry id to undo (defaults to the most recent entry)
+        id: Option<u64>,
+    },
+
+    /// Show recent state mutations, most recent first
+    Oplog {
+        /// Maximum number of entries to show
+        #[arg(long, short, default_value_t = 10)]
+        limit: usize,
+    },
+
+    /// Garbage-collect administrative entries for worktrees deleted by hand
+    Prune,
+
+    /// Repair worktree admin links after the repo or a worktree was moved
+    Repair {
+        /// Specific worktree name(s) to repair (defaults to all)
+        names: Vec<String>,
+    },
 }
 
 #[derive(Subcommand, Debug)]
@@ -191,6 +434,75 @@ pub enum ConfigCommands {
         unset: bool,
     },
 
+    /// Get or set on-remove hook
+    OnRemove {
+        /// Command to run
+        command: Option<String>,
+
+        /// Remove the hook
+        #[arg(long)]
+        unset: bool,
+    },
+
+    /// Get or set on-exit hook
+    OnExit {
+        /// Command to run
+        command: Option<String>,
+
+        /// Remove the hook
+        #[arg(long)]
+        unset: bool,
+    },
+
+    /// Get or set pre-merge hook (a non-zero exit aborts the merge)
+    PreMerge {
+        /// Command to run
+        command: Option<String>,
+
+        /// Remove the hook
+        #[arg(long)]
+        unset: bool,
+    },
+
+    /// Get or set post-merge hook
+    PostMerge {
+        /// Command to run
+        command: Option<String>,
+
+        /// Remove the hook
+        #[arg(long)]
+        unset: bool,
+    },
+
     /// Show current configuration (default)
     Show,
 }
+
+/// Print a failed command's error per `--json-errors`, returning the exit
+/// code the process should use. With `json_errors`, downcasts to a
+/// [`wt_core::Error`] and prints its [`Error::report`] as one line of JSON;
+/// errors from outside `wt-core` (a bare `anyhow::Error` from a dependency)
+/// fall back to the same prose either way, since there's no stable `code`
+/// to give them.
+pub fn report_error(err: &anyhow::Error, json_errors: bool) -> i32 {
+    let wt_error = err.downcast_ref::<Error>();
+
+    if json_errors {
+        let report = match wt_error {
+            Some(e) => e.report(),
+            None => wt_core::ErrorReport {
+                code: "unknown".to_string(),
+                message: err.to_string(),
+                details: serde_json::Value::Null,
+            },
+        };
+        match serde_json::to_string(&report) {
+            Ok(line) => eprintln!("{}", line),
+            Err(_) => eprintln!("{}", err),
+        }
+    } else {
+        eprintln!("Error: {}", err);
+    }
+
+    wt_error.map(Error::exit_code).unwrap_or(wt_core::error::exit_code::GENERIC)
+}
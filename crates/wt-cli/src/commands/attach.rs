@@ -1,10 +1,15 @@
 //! Attach command - attach to tmux session
+//!
+//! `name` may be a worker name, `wt_core::spawn::PREVIOUS_WINDOW` ("-") to
+//! toggle back to whichever window was current before the last attach, or
+//! `None` to default to the session's last-active window (or, if already
+//! inside the session, the most recently attached-to worker).
 
 use anyhow::Result;
 
 use wt_core::spawn;
 
-pub fn run(name: Option<&str>) -> Result<()> {
-    spawn::attach(name)?;
+pub fn run(name: Option<&str>, read_only: bool, detach_others: bool) -> Result<()> {
+    spawn::attach(name, read_only, detach_others)?;
     Ok(())
 }
@@ -0,0 +1,100 @@
+//! Shell completion scripts with dynamic worker-name completion
+//!
+//! clap's static completions describe subcommands and flags fine, but can't
+//! know which worktrees/workers exist right now. These hand-written scripts
+//! query `wt ps -q` for that — live names, not a snapshot baked in at build
+//! time — the way a tmux-shortener completion function queries its own
+//! `list -q` for candidates.
+
+use anyhow::Result;
+
+/// Subcommands whose first positional argument is a worker/worktree name,
+/// kept in sync with `Commands` in `cli.rs`.
+const NAME_ARG_COMMANDS: &[&str] = &[
+    "attach", "remove", "rm", "kill", "merge", "review", "status", "lock", "unlock", "move",
+    "repair", "open", "o", "unstash", "reset", "logs", "switch", "tag", "untag", "sync",
+];
+
+pub fn run(shell: &str) -> Result<()> {
+    let script = match shell.to_lowercase().as_str() {
+        "bash" => bash_completion(),
+        "zsh" => zsh_completion(),
+        "fish" => fish_completion(),
+        _ => {
+            eprintln!("Unsupported shell: {}", shell);
+            eprintln!("Supported shells: bash, zsh, fish");
+            std::process::exit(1);
+        }
+    };
+
+    println!("{}", script.trim());
+    Ok(())
+}
+
+fn bash_completion() -> String {
+    format!(
+        r#"
+_wt_names() {{
+    command wt ps -q "$1" 2>/dev/null
+}}
+
+_wt_complete() {{
+    local cur cmd
+    cur="${{COMP_WORDS[COMP_CWORD]}}"
+    cmd="${{COMP_WORDS[1]}}"
+
+    case "$cmd" in
+        {commands})
+            if [[ "$COMP_CWORD" -eq 2 ]]; then
+                COMPREPLY=( $(compgen -W "$(_wt_names "$cur")" -- "$cur") )
+                return 0
+            fi
+            ;;
+    esac
+
+    COMPREPLY=()
+}}
+
+complete -F _wt_complete wt
+"#,
+        commands = NAME_ARG_COMMANDS.join("|")
+    )
+}
+
+fn zsh_completion() -> String {
+    format!(
+        r#"
+_wt_names() {{
+    command wt ps -q "$1" 2>/dev/null
+}}
+
+_wt() {{
+    local cmd="${{words[2]}}"
+    case "$cmd" in
+        {commands})
+            if [[ "$CURRENT" -eq 3 ]]; then
+                compadd -- $(_wt_names "$PREFIX")
+                return
+            fi
+            ;;
+    esac
+}}
+
+compdef _wt wt
+"#,
+        commands = NAME_ARG_COMMANDS.join("|")
+    )
+}
+
+fn fish_completion() -> String {
+    format!(
+        r#"
+function __wt_names
+    command wt ps -q (commandline -ct) 2>/dev/null
+end
+
+complete -c wt -n '__fish_seen_subcommand_from {commands}' -f -a '(__wt_names)'
+"#,
+        commands = NAME_ARG_COMMANDS.join(" ")
+    )
+}
@@ -0,0 +1,207 @@
+//! Health command - report on tmux/git/adapter availability and repo scaffolding
+
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+
+use wt_core::{adapter, config, git, terminal, WtToml};
+
+/// A shelled-out command `wt` depends on: whether it's on `PATH`, the
+/// version string it reports (best-effort — `None` if it couldn't be
+/// parsed), and whether its absence should fail `--json`'s exit code.
+#[derive(Debug, Serialize)]
+struct CommandCheck {
+    name: String,
+    available: bool,
+    version: Option<String>,
+    required: bool,
+}
+
+/// A file `wt` expects to find (or scaffold) in the repo.
+#[derive(Debug, Serialize)]
+struct FileCheck {
+    path: String,
+    present: bool,
+    required: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RepoReport {
+    name: String,
+    base_branch: Option<String>,
+    agent: String,
+    remote_host: Option<String>,
+    remote_reachable: Option<bool>,
+}
+
+/// The full `wt health` result set, serialized as-is for `--json`.
+#[derive(Debug, Serialize)]
+struct HealthReport {
+    version: String,
+    commands: Vec<CommandCheck>,
+    repo: Option<RepoReport>,
+    files: Vec<FileCheck>,
+    /// False when a required command or file is missing — drives the
+    /// `--json` exit code.
+    ok: bool,
+}
+
+pub fn run(json: bool) -> Result<()> {
+    let mut commands = Vec::new();
+    for dep in terminal::check_dependencies() {
+        commands.push(CommandCheck {
+            version: command_version(&dep.name),
+            name: dep.name,
+            available: dep.available,
+            required: dep.required,
+        });
+    }
+
+    let mut files = Vec::new();
+    let mut repo_report = None;
+
+    if let Ok(repo) = git::get_base_repo() {
+        let name = repo
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        let toml = WtToml::load(&repo)?;
+        files.push(FileCheck {
+            path: "wt.toml".to_string(),
+            present: repo.join("wt.toml").exists(),
+            required: false,
+        });
+
+        let agent = toml
+            .as_ref()
+            .map(|t| t.adapter(None))
+            .unwrap_or_else(|| adapter::resolve(None, None));
+        commands.push(CommandCheck {
+            version: command_version(agent.command()),
+            name: agent.command().to_string(),
+            available: terminal::command_exists(agent.command()),
+            required: false,
+        });
+        if let Some(skills_dir) = agent.skills_dir() {
+            files.push(FileCheck {
+                path: skills_dir.to_string(),
+                present: repo.join(skills_dir).exists(),
+                required: false,
+            });
+        }
+
+        let remote = toml.as_ref().and_then(|t| t.remote.as_ref());
+        repo_report = Some(RepoReport {
+            name,
+            base_branch: config::get_base_branch().ok(),
+            agent: agent.name().to_string(),
+            remote_host: remote.map(|r| r.host.clone()),
+            remote_reachable: remote.map(|r| ssh_reachable(&r.host)),
+        });
+    }
+
+    let ok = commands.iter().all(|c| !c.required || c.available) && files.iter().all(|f| !f.required || f.present);
+
+    let report = HealthReport {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        commands,
+        repo: repo_report,
+        files,
+        ok,
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_human(&report);
+    }
+
+    if !report.ok {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+fn print_human(report: &HealthReport) {
+    eprintln!("{} {}", "wt".bold(), report.version.cyan());
+    eprintln!();
+
+    eprintln!("System");
+    for check in &report.commands {
+        print_command_check(check);
+    }
+
+    match &report.repo {
+        Some(repo) => {
+            eprintln!();
+            eprintln!("Repository: {}", repo.name);
+
+            for file in &report.files {
+                print_file_check(file);
+            }
+
+            eprintln!();
+            eprintln!("Agent: {}", repo.agent);
+
+            if let Some(host) = &repo.remote_host {
+                eprintln!();
+                eprintln!("Remote: {}", host);
+                if repo.remote_reachable == Some(true) {
+                    eprintln!("  {} ssh {}", "✓".green(), host);
+                } else {
+                    eprintln!("  {} ssh {} (unreachable)", "✗".red(), host);
+                }
+            }
+        }
+        None => {
+            eprintln!();
+            eprintln!("Repository: not in a git repository");
+        }
+    }
+}
+
+fn print_command_check(check: &CommandCheck) {
+    let suffix = match (&check.version, check.available) {
+        (Some(version), true) => format!(" ({})", version),
+        (None, false) => " (not found)".to_string(),
+        _ => String::new(),
+    };
+    if check.available {
+        eprintln!("  {} {}{}", "✓".green(), check.name, suffix);
+    } else {
+        eprintln!("  {} {}{}", "✗".red(), check.name, suffix);
+    }
+}
+
+fn print_file_check(check: &FileCheck) {
+    if check.present {
+        eprintln!("  {} {}", "✓".green(), check.path);
+    } else {
+        eprintln!("  {} {} (missing)", "✗".red(), check.path);
+    }
+}
+
+/// Best-effort `<cmd> --version`, trimmed to its first line. `None` if the
+/// command isn't on `PATH` or doesn't understand `--version`.
+fn command_version(cmd: &str) -> Option<String> {
+    let output = std::process::Command::new(cmd).arg("--version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).lines().next().map(str::to_string)
+}
+
+/// Check SSH reachability of a `[remote]` host with a non-interactive,
+/// no-op connection (`BatchMode` skips password prompts so this never
+/// hangs waiting on input; `true` is the cheapest command that proves the
+/// connection and auth actually work).
+fn ssh_reachable(host: &str) -> bool {
+    std::process::Command::new("ssh")
+        .args(["-o", "BatchMode=yes", "-o", "ConnectTimeout=5", host, "true"])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
@@ -2,10 +2,11 @@
 
 use anyhow::Result;
 use colored::Colorize;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
-use wt_core::{config, git, Error};
+use wt_core::{adapter, config, git, preflight, template, Error, WtToml};
 
 const WT_TOML_CONTENT: &str = r#"[spawn]
 auto = false
@@ -77,6 +78,21 @@ Issues are tracked in the `issues/` directory as markdown files:
 ```
 "#;
 
+const TASK_MD_TEMPLATE: &str = r#"# Task: {{ name }}
+
+Branch: {{ branch }}
+Issue: {{ issue }}
+
+## Context
+
+{{ context }}
+"#;
+
+const SPAWN_PROMPT_TEMPLATE: &str = "Read .wt/task.md and begin work on the task.\n\n{{ context }}";
+
+const STATUS_TEMPLATE: &str = r#"{{ name }} ({{ branch }}) - spawned {{ date }}
+"#;
+
 const SETTINGS_JSON: &str = r#"{
   "permissions": {
     "allow": [
@@ -105,7 +121,12 @@ const SETTINGS_JSON: &str = r#"{
 }
 "#;
 
-pub fn run(force: bool, backup: bool, audit: bool) -> Result<()> {
+pub fn run(force: bool, backup: bool, audit: bool, vars: &[String], tags: &[String]) -> Result<()> {
+    // Check every dependency up front so a fresh machine missing both git
+    // and tmux hears about both at once, not one fix-one-rerun cycle at a
+    // time.
+    preflight::check()?;
+
     let repo = git::get_base_repo()?;
 
     // Check if already initialized
@@ -115,8 +136,20 @@ pub fn run(force: bool, backup: bool, audit: bool) -> Result<()> {
 
     eprintln!("{} Initializing wt in {}", "→".cyan(), repo.display());
 
+    // Resolve the configured adapter (falling back to the built-in `claude`
+    // default on a first init, before wt.toml exists) so the skills
+    // directory we scaffold matches whatever agent this repo actually
+    // drives, rather than assuming Claude Code.
+    let agent = WtToml::load(&repo)?
+        .as_ref()
+        .map(|toml| toml.adapter(None))
+        .unwrap_or_else(|| adapter::resolve(None, None));
+
     // Create directories
-    let dirs = ["docs", "issues", ".claude/commands"];
+    let mut dirs = vec!["docs", "issues", "templates"];
+    if let Some(skills_dir) = agent.skills_dir() {
+        dirs.push(skills_dir);
+    }
     for dir in dirs {
         let path = repo.join(dir);
         if !path.exists() {
@@ -125,48 +158,143 @@ pub fn run(force: bool, backup: bool, audit: bool) -> Result<()> {
         }
     }
 
+    let vars = resolve_vars(&repo, vars)?;
+
     // Write wt.toml
     write_file(&repo.join("wt.toml"), WT_TOML_CONTENT, force, backup)?;
 
     // Write CLAUDE.md
-    write_file(&repo.join("CLAUDE.md"), CLAUDE_MD_TEMPLATE, force, backup)?;
+    write_rendered(
+        &repo,
+        &repo.join("CLAUDE.md"),
+        "CLAUDE.md",
+        CLAUDE_MD_TEMPLATE,
+        &vars,
+        force,
+        backup,
+    )?;
 
     // Write docs files
-    write_file(
+    write_rendered(
+        &repo,
         &repo.join("docs/index.md"),
+        "docs/index.md",
         DOCS_INDEX_TEMPLATE,
+        &vars,
         force,
         backup,
     )?;
-    write_file(
+    write_rendered(
+        &repo,
         &repo.join("docs/issue-tracking.md"),
+        "docs/issue-tracking.md",
         ISSUE_TRACKING_TEMPLATE,
+        &vars,
         force,
         backup,
     )?;
 
-    // Write .claude/settings.json
+    // Write .claude/settings.json -- the permissions format here is
+    // Claude Code's own, so only scaffold it for that adapter; other
+    // adapters get their skills_dir created above and nothing else, until
+    // they have a settings format of their own to template.
+    if agent.name() == "claude" {
+        write_file(
+            &repo.join(".claude/settings.json"),
+            SETTINGS_JSON,
+            force,
+            backup,
+        )?;
+    }
+
+    // Write templates/ scaffold (task.md, spawn-prompt, status)
+    write_file(
+        &repo.join("templates/task.md"),
+        TASK_MD_TEMPLATE,
+        force,
+        backup,
+    )?;
+    write_file(
+        &repo.join("templates/spawn-prompt"),
+        SPAWN_PROMPT_TEMPLATE,
+        force,
+        backup,
+    )?;
     write_file(
-        &repo.join(".claude/settings.json"),
-        SETTINGS_JSON,
+        &repo.join("templates/status"),
+        STATUS_TEMPLATE,
         force,
         backup,
     )?;
 
+    // Register this repo in the global project registry so `wt projects`/
+    // `wt workon` can find it from anywhere, not just from inside it.
+    let project_name = vars
+        .get("project_name")
+        .cloned()
+        .unwrap_or_else(|| repo.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default());
+    let mut global_config = config::Config::load()?;
+    global_config.register_project(&repo, &project_name, tags);
+    global_config.save()?;
+
     eprintln!();
     eprintln!("{} Initialization complete", "✓".green().bold());
 
     if audit {
         eprintln!();
         eprintln!(
-            "{} Run 'claude' to audit and customize documentation",
-            "→".dimmed()
+            "{} Run '{}' to audit and customize documentation",
+            "→".dimmed(),
+            agent.command()
         );
     }
 
     Ok(())
 }
 
+/// Build the `{{ key }}` substitution map for init templates: `project_name`
+/// (the repo directory's name), overlaid with `[init.vars]` from an existing
+/// wt.toml (present on a `--force` re-init), overlaid with `--var` flags.
+fn resolve_vars(repo: &Path, cli_vars: &[String]) -> Result<HashMap<String, String>> {
+    let mut vars = HashMap::new();
+    vars.insert(
+        "project_name".to_string(),
+        repo.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default(),
+    );
+
+    if let Some(toml) = WtToml::load(repo)? {
+        vars.extend(toml.init.vars);
+    }
+
+    for raw in cli_vars {
+        let (key, value) = raw
+            .split_once('=')
+            .ok_or_else(|| Error::Custom(format!("invalid --var '{}', expected key=value", raw)))?;
+        vars.insert(key.to_string(), value.to_string());
+    }
+
+    Ok(vars)
+}
+
+/// Write an init template, preferring a `.wt/templates/<name>` override over
+/// the embedded default, with `{{ key }}` variables substituted.
+fn write_rendered(
+    repo: &Path,
+    dest: &Path,
+    template_name: &str,
+    default: &str,
+    vars: &HashMap<String, String>,
+    force: bool,
+    backup: bool,
+) -> Result<()> {
+    let source = template::load_init_override(repo, template_name, default);
+    let render_vars: HashMap<&str, String> = vars.iter().map(|(k, v)| (k.as_str(), v.clone())).collect();
+    let rendered = template::render(&source, &render_vars);
+    write_file(dest, &rendered, force, backup)
+}
+
 fn write_file(path: &Path, content: &str, force: bool, backup: bool) -> Result<()> {
     let name = path.file_name().unwrap().to_string_lossy();
 
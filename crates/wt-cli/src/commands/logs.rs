@@ -0,0 +1,28 @@
+//! Logs command - tail a worker's pane output without attaching
+
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use wt_core::spawn;
+
+pub fn run(name: &str, lines: usize, follow: bool) -> Result<()> {
+    let mut printed = spawn::logs(name, Some(lines))?;
+    print!("{}", printed);
+
+    while follow {
+        thread::sleep(Duration::from_secs(1));
+
+        let captured = spawn::logs(name, Some(lines))?;
+        if let Some(new_output) = captured.strip_prefix(&printed) {
+            print!("{}", new_output);
+        } else {
+            // The pane scrolled past our last snapshot; reprint from scratch.
+            print!("{}", captured);
+        }
+        printed = captured;
+    }
+
+    Ok(())
+}
@@ -2,33 +2,135 @@
 
 use anyhow::Result;
 use colored::Colorize;
+use std::collections::HashMap;
+use std::io::{self, Write};
 
-use wt_core::{git, spawn, Error};
+use wt_core::{config, git, spawn, Error, HookEvent, MergeFailure, MergeOutcome, Strategy};
 
-pub fn run(name: &str) -> Result<()> {
+pub fn run(name: &str, ff_only: bool, no_ff: bool, rebase: bool) -> Result<()> {
     let worktrees_dir = git::get_worktrees_dir()?;
     let worktree_path = worktrees_dir.join(name);
 
     if !worktree_path.exists() {
-        return Err(Error::WorktreeNotFound(name.to_string()).into());
+        return Err(Error::WorktreeNotFound {
+            name: name.to_string(),
+            candidates: git::list_worktree_names(&worktrees_dir).unwrap_or_default(),
+        }
+        .into());
     }
 
-    // Check for uncommitted changes
-    if git::has_uncommitted_changes(&worktree_path)? {
+    // Check for uncommitted changes, surfacing the same staged/modified/
+    // untracked breakdown `wt remove` warns with instead of a bare boolean.
+    let status_entries = git::get_status(&worktree_path)?;
+    let summary = git::summarize_status(&status_entries);
+    if summary != "clean" {
+        eprintln!(
+            "{} '{}' has uncommitted changes: {}",
+            "✗".red(),
+            name.cyan(),
+            summary
+        );
         return Err(Error::UncommittedChanges.into());
     }
 
     // Get branch name
     let branch = git::get_worktree_branch(&worktree_path)?;
+    let base_branch = config::get_base_branch()?;
 
-    // Merge the branch
-    git::merge_branch(&branch)?;
+    // Unlike on-create/on-remove, a failing pre-merge hook aborts the merge
+    // outright — it exists to let a test suite or linter block a bad merge.
+    if let Some(hook) = config::get_repo_hook(HookEvent::PreMerge)? {
+        let passed =
+            config::run_lifecycle_hook(&hook, HookEvent::PreMerge, &worktree_path, name, &branch, &base_branch)?;
+        if !passed {
+            return Err(Error::Custom(format!(
+                "pre-merge hook failed, aborting merge of '{}'",
+                name
+            ))
+            .into());
+        }
+    }
+
+    // Classify and perform the merge via libgit2's merge-analysis: up to
+    // date, fast-forward, or a real three-way merge that might conflict. With
+    // --rebase, replay the worktree's own commits onto the base branch first
+    // so the merge that follows is always a fast-forward, for linear history.
+    let base_repo = git::get_base_repo()?;
+    let strategy = if rebase { Strategy::Rebase } else { Strategy::Merge };
+    let outcome = if rebase {
+        wt_core::integrate_worktree(&worktree_path, &base_repo, &branch, &base_branch, strategy)?
+    } else {
+        wt_core::gitbackend::merge_branch_analyzed(&base_repo, &branch, ff_only, no_ff)?
+    };
+    let outcome = match outcome {
+        Ok(outcome) => outcome,
+        Err(MergeFailure::NotFastForward) => {
+            return Err(Error::Custom(format!(
+                "'{}' can't be fast-forwarded; rerun without --ff-only",
+                branch
+            ))
+            .into());
+        }
+        Err(MergeFailure::Conflicts(paths)) => {
+            let (verb, location) = if rebase {
+                ("Rebase", worktree_path.display().to_string())
+            } else {
+                ("Merge", base_repo.display().to_string())
+            };
+            eprintln!(
+                "{} {} of '{}' conflicted in {} file(s):",
+                "✗".red(),
+                verb,
+                branch.cyan(),
+                paths.len()
+            );
+            for path in &paths {
+                eprintln!("  {}", path);
+            }
+            eprintln!("  {} Resolve the conflicts in {} and commit", "→".dimmed(), location);
+            return Err(Error::Custom(format!("{} of '{}' conflicted", verb.to_lowercase(), branch)).into());
+        }
+    };
+
+    match outcome {
+        MergeOutcome::UpToDate => {
+            eprintln!(
+                "{} '{}' is already up to date with '{}'",
+                "✓".green(),
+                base_branch.cyan(),
+                branch.cyan()
+            );
+            return Ok(());
+        }
+        MergeOutcome::FastForwarded => {
+            eprintln!(
+                "{} Fast-forwarded to '{}'",
+                "✓".green(),
+                branch.cyan()
+            );
+        }
+        MergeOutcome::Merged => {
+            eprintln!(
+                "{} Merged branch '{}' into current branch",
+                "✓".green(),
+                branch.cyan()
+            );
+        }
+    }
 
-    eprintln!(
-        "{} Merged branch '{}' into current branch",
-        "✓".green(),
-        branch.cyan()
-    );
+    // Run the repo's wt.toml on_merge hook (best-effort)
+    let wt_toml = config::read_layered_wt_toml(Some(&worktree_path))?;
+    if let Some(hook) = &wt_toml.hooks.on_merge {
+        let mut vars = HashMap::new();
+        vars.insert("name", name.to_string());
+        vars.insert("branch", branch.clone());
+        config::run_hook(hook, &worktree_path, &vars)?;
+    }
+
+    // Run the `wt config post-merge` hook, if set (also best-effort)
+    if let Some(hook) = config::get_repo_hook(HookEvent::PostMerge)? {
+        config::run_lifecycle_hook(&hook, HookEvent::PostMerge, &worktree_path, name, &branch, &base_branch)?;
+    }
 
     // Unregister from spawn state
     spawn::unregister(name)?;
@@ -36,12 +138,22 @@ pub fn run(name: &str) -> Result<()> {
     // Kill tmux window if running
     spawn::kill_window(name)?;
 
-    eprintln!();
-    eprintln!(
-        "  {} Remove worktree with: {}",
-        "→".dimmed(),
-        format!("wt remove {}", name).cyan()
-    );
+    // The merge went through cleanly (no conflicts), so the worktree has
+    // nothing left to contribute — offer to remove it on the spot instead of
+    // leaving that as a manual follow-up step.
+    eprint!("  Remove worktree '{}'? [y/N] ", name);
+    io::stderr().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    if input.trim().eq_ignore_ascii_case("y") {
+        super::remove::run(name, false, false)?;
+    } else {
+        eprintln!(
+            "  {} Remove it later with: {}",
+            "→".dimmed(),
+            format!("wt remove {}", name).cyan()
+        );
+    }
 
     Ok(())
 }
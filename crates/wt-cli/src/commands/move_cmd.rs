@@ -0,0 +1,47 @@
+//! Move command - relocate a worktree directory
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::{git, Error, OrchestratorState};
+
+pub fn run(name: &str, to: &str) -> Result<()> {
+    let worktrees_dir = git::get_worktrees_dir()?;
+    let from_path = worktrees_dir.join(name);
+    let to_path = worktrees_dir.join(to);
+
+    if !from_path.exists() {
+        return Err(Error::WorktreeNotFound {
+            name: name.to_string(),
+            candidates: git::list_worktree_names(&worktrees_dir).unwrap_or_default(),
+        }
+        .into());
+    }
+    if to_path.exists() {
+        return Err(Error::WorktreeExists(to.to_string()).into());
+    }
+
+    if let Some(parent) = to_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    git::move_worktree(&from_path, &to_path)?;
+
+    // Keep spawn state's worker name and recorded worktree path in sync.
+    let repo_root = git::get_base_repo()?;
+    if let Some(mut state) = OrchestratorState::load(&repo_root)? {
+        if let Some(worker) = state.get_worker_by_name_mut(name) {
+            worker.name = to.to_string();
+            worker.worktree_path = to_path.clone();
+            state.save()?;
+        }
+    }
+
+    eprintln!(
+        "{} Moved worktree '{}' to '{}'",
+        "✓".green(),
+        name.cyan(),
+        to.cyan()
+    );
+    Ok(())
+}
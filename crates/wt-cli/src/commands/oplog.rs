@@ -0,0 +1,27 @@
+//! Oplog command - show recent state mutations
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::{format_age, git, OpLog};
+
+pub fn run(limit: usize) -> Result<()> {
+    let repo_root = git::get_base_repo()?;
+    let log = OpLog::load(&repo_root)?;
+
+    if log.entries.is_empty() {
+        eprintln!("No recorded operations");
+        return Ok(());
+    }
+
+    for entry in log.entries.iter().rev().take(limit) {
+        eprintln!(
+            "{:4} {:8} {}",
+            format!("#{}", entry.id).dimmed(),
+            format_age(entry.timestamp).dimmed(),
+            entry.description
+        );
+    }
+
+    Ok(())
+}
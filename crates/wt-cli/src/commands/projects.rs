@@ -0,0 +1,36 @@
+//! Projects command - list every repo registered via `wt init`
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::config::Config;
+
+pub fn run(tag: Option<&str>) -> Result<()> {
+    let config = Config::load()?;
+    let projects = config.projects(tag);
+
+    if projects.is_empty() {
+        if let Some(tag) = tag {
+            eprintln!("No registered projects tagged '{}'", tag);
+        } else {
+            eprintln!("No registered projects. Run 'wt init' in a repo to register it.");
+        }
+        return Ok(());
+    }
+
+    for project in &projects {
+        let tags = if project.tags.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", project.tags.join(", "))
+        };
+        println!(
+            "{}{} {}",
+            project.name.bold(),
+            tags.dimmed(),
+            project.path.display().to_string().dimmed()
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+//! Prune command - garbage-collect stale worktree admin entries
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::git;
+
+pub fn run() -> Result<()> {
+    git::prune_worktrees()?;
+    eprintln!("{} Pruned stale worktree entries", "✓".green());
+    Ok(())
+}
@@ -2,25 +2,63 @@
 
 use anyhow::Result;
 use colored::Colorize;
+use std::collections::BTreeMap;
 
-use wt_core::spawn::{self, TaskStatus};
+use wt_core::git::RichStatus;
+use wt_core::spawn::{self, TaskInfo, TaskStatus, WindowRecency};
+use wt_core::config;
 
-pub fn run() -> Result<()> {
-    let tasks = spawn::list_tasks()?;
+pub fn run(quiet: bool, prefix: Option<&str>, tag: Option<&str>) -> Result<()> {
+    if quiet {
+        for name in spawn::list_task_names(prefix)? {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    let wt_toml = config::read_layered_wt_toml(None)?;
+    let mut tasks = if wt_toml.projects.is_empty() {
+        spawn::list_tasks()?
+    } else {
+        spawn::list_all_tasks(&wt_toml)?
+    };
+
+    if let Some(tag) = tag {
+        tasks.retain(|t| t.tags.iter().any(|t| t == tag));
+    }
 
     if tasks.is_empty() {
         eprintln!("No spawned sessions");
         return Ok(());
     }
 
-    // Print header
+    // Sessions only need grouping by repo once there's more than one to
+    // report; a single-repo `wt ps` (the common case) stays a flat table.
+    let mut by_repo: BTreeMap<String, Vec<TaskInfo>> = BTreeMap::new();
+    for task in tasks {
+        by_repo.entry(task.repo.clone()).or_default().push(task);
+    }
+
+    let grouped = by_repo.len() > 1;
+    for (repo, tasks) in by_repo {
+        if grouped {
+            eprintln!("{} {}", "▸".cyan(), repo.bold());
+        }
+        print_table(&tasks);
+    }
+
+    Ok(())
+}
+
+fn print_table(tasks: &[TaskInfo]) {
     eprintln!(
-        "{:16} {:12} {:20} {:8} {}",
+        "{:2} {:16} {:12} {:20} {:8} {}",
+        "",
         "NAME".bold(),
         "STATUS".bold(),
         "BRANCH".bold(),
         "COMMITS".bold(),
-        "DIRTY".bold()
+        "GIT".bold()
     );
 
     for task in tasks {
@@ -32,21 +70,65 @@ pub fn run() -> Result<()> {
             }
         };
 
-        let dirty_indicator = if task.is_dirty {
-            "‚óè".yellow().to_string()
-        } else {
-            "-".dimmed().to_string()
-        };
-
         eprintln!(
-            "{:16} {:12} {:20} {:8} {}",
+            "{:2} {:16} {:12} {:20} {:8} {}",
+            recency_marker(task.recency),
             task.name.cyan(),
             status_color,
             task.branch,
             task.commits_ahead,
-            dirty_indicator
+            render_git_status(&task.git_status)
         );
     }
+}
+
+/// Marker for the window `wt attach`/`wt switch` would land on (`*`) and
+/// the one `wt attach -`/`wt switch` (no name) would toggle back to (`-`),
+/// so both are visible at a glance without inspecting state by hand.
+fn recency_marker(recency: WindowRecency) -> String {
+    match recency {
+        WindowRecency::Current => "*".green().to_string(),
+        WindowRecency::Previous => "-".dimmed().to_string(),
+        WindowRecency::Other => " ".to_string(),
+    }
+}
 
-    Ok(())
+/// Render a [`RichStatus`] as compact, prompt-style segments (e.g. `⇡3 ⇣1 +2
+/// !1 ?4`), each colored per category so a worker needing attention (a
+/// conflict, a divergence) stands out at a glance.
+fn render_git_status(status: &RichStatus) -> String {
+    if status.is_clean() {
+        return "-".dimmed().to_string();
+    }
+
+    let mut parts = Vec::new();
+    if status.ahead > 0 {
+        parts.push(format!("⇡{}", status.ahead).cyan().to_string());
+    }
+    if status.behind > 0 {
+        parts.push(format!("⇣{}", status.behind).cyan().to_string());
+    }
+    if status.staged > 0 {
+        parts.push(format!("+{}", status.staged).green().to_string());
+    }
+    if status.modified > 0 {
+        parts.push(format!("!{}", status.modified).yellow().to_string());
+    }
+    if status.untracked > 0 {
+        parts.push(format!("?{}", status.untracked).blue().to_string());
+    }
+    if status.renamed > 0 {
+        parts.push(format!("»{}", status.renamed).magenta().to_string());
+    }
+    if status.deleted > 0 {
+        parts.push(format!("✘{}", status.deleted).red().to_string());
+    }
+    if status.conflicted > 0 {
+        parts.push(format!("={}", status.conflicted).red().bold().to_string());
+    }
+    if status.stashed {
+        parts.push("$".dimmed().to_string());
+    }
+
+    parts.join(" ")
 }
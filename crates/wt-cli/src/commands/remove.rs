@@ -3,10 +3,12 @@
 use anyhow::Result;
 use colored::Colorize;
 use glob::Pattern;
+use std::collections::HashMap;
 
-use wt_core::{git, Error};
+use wt_core::worktree::WorktreeRemoveFailure;
+use wt_core::{config, git, spawn, Error, HookEvent, Worktree};
 
-pub fn run(pattern: &str, force: bool) -> Result<()> {
+pub fn run(pattern: &str, force: bool, stash: bool) -> Result<()> {
     let worktrees_dir = git::get_worktrees_dir()?;
     let worktrees = git::list_worktrees()?;
 
@@ -23,29 +25,113 @@ pub fn run(pattern: &str, force: bool) -> Result<()> {
         // If not a pattern match, try exact match
         let exact_path = worktrees_dir.join(pattern.as_str());
         if exact_path.exists() {
-            remove_single(&exact_path, pattern.as_str(), force)?;
+            remove_single(&exact_path, pattern.as_str(), force, stash)?;
             return Ok(());
         }
-        return Err(Error::WorktreeNotFound(pattern.as_str().to_string()).into());
+        return Err(Error::WorktreeNotFound {
+            name: pattern.as_str().to_string(),
+            candidates: worktrees,
+        }
+        .into());
     }
 
     // Remove each matching worktree
     for name in matching {
         let path = worktrees_dir.join(&name);
-        remove_single(&path, &name, force)?;
+        remove_single(&path, &name, force, stash)?;
     }
 
     Ok(())
 }
 
-fn remove_single(path: &std::path::Path, name: &str, force: bool) -> Result<()> {
-    // Check for uncommitted changes unless force
-    if !force && git::has_uncommitted_changes(path)? {
-        return Err(Error::UncommittedChanges.into());
+fn remove_single(path: &std::path::Path, name: &str, force: bool, stash: bool) -> Result<()> {
+    let branch = git::get_worktree_branch(path).unwrap_or_default();
+    let wt_toml = config::read_layered_wt_toml(Some(path))?;
+    let stash = stash || wt_toml.stash_on_remove;
+
+    // Run the safe-removal pre-checks unless force, surfacing which guard
+    // tripped so the caller gets a specific message rather than a generic
+    // git error.
+    if !force {
+        let worktree = Worktree {
+            name: name.to_string(),
+            path: path.to_path_buf(),
+            branch: branch.clone(),
+        };
+        let base_branch = config::get_base_branch()?;
+
+        if let Err(failure) = worktree.check_removable(&base_branch) {
+            match failure {
+                WorktreeRemoveFailure::UncommittedChanges(summary) => {
+                    if stash {
+                        let stashed = worktree.stash()?;
+                        if stashed {
+                            eprintln!(
+                                "  {} Stashed uncommitted changes in '{}' ({}); recover with {}",
+                                "→".dimmed(),
+                                name.cyan(),
+                                summary,
+                                format!("wt unstash {}", name).cyan()
+                            );
+                        }
+                    } else {
+                        eprintln!(
+                            "{} '{}' has uncommitted changes: {}",
+                            "✗".red(),
+                            name.cyan(),
+                            summary
+                        );
+                        return Err(Error::UncommittedChanges.into());
+                    }
+                }
+                WorktreeRemoveFailure::Unmerged(commits) => {
+                    eprintln!(
+                        "{} '{}' isn't merged — {} commit(s) would be lost. Use --force to remove anyway",
+                        "✗".red(),
+                        name.cyan(),
+                        commits
+                    );
+                    return Err(Error::Custom(format!(
+                        "branch isn't merged — {} commit(s) would be lost",
+                        commits
+                    ))
+                    .into());
+                }
+                WorktreeRemoveFailure::Error(msg) => {
+                    return Err(Error::Custom(msg).into());
+                }
+            }
+        }
     }
 
+    // Run the on_remove hooks before tearing down the worktree. Like
+    // on-create, a failing hook is logged but never blocks the removal.
+    if let Some(hook) = &wt_toml.hooks.on_remove {
+        let mut vars = HashMap::new();
+        vars.insert("name", name.to_string());
+        config::run_hook(hook, path, &vars)?;
+    }
+    if let Some(hook) = config::get_repo_hook(HookEvent::OnRemove)? {
+        config::run_lifecycle_hook(&hook, HookEvent::OnRemove, path, name, &branch, "")?;
+    }
+
+    // Stop any background services and unregister from spawn state
+    spawn::unregister(name)?;
+
     git::remove_worktree(path, force)?;
 
+    // Clean up the now-unused branch, unless it's protected (e.g. `main`).
+    if !branch.is_empty() {
+        if let Err(e) = git::delete_branch(&branch, force, &wt_toml.persistent_branches) {
+            eprintln!(
+                "  {} Kept branch '{}': {}",
+                "→".dimmed(),
+                branch.cyan(),
+                e
+            );
+        }
+    }
+
     // Clean up empty parent directories (for nested paths)
     let mut parent = path.parent();
     let worktrees_dir = git::get_worktrees_dir()?;
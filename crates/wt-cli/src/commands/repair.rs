@@ -0,0 +1,16 @@
+//! Repair command - fix worktree admin links after a move
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::git;
+
+pub fn run(names: &[String]) -> Result<()> {
+    let worktrees_dir = git::get_worktrees_dir()?;
+    let paths: Vec<_> = names.iter().map(|name| worktrees_dir.join(name)).collect();
+
+    git::repair_worktrees(&paths)?;
+
+    eprintln!("{} Repaired worktree admin links", "✓".green());
+    Ok(())
+}
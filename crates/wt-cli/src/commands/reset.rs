@@ -0,0 +1,40 @@
+//! Reset command - discard a worktree's uncommitted changes back to its branch HEAD
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::{git, gitbackend, Error};
+
+pub fn run(name: &str, staged_only: bool, force: bool) -> Result<()> {
+    let worktrees_dir = git::get_worktrees_dir()?;
+    let path = worktrees_dir.join(name);
+
+    if !path.exists() {
+        return Err(Error::WorktreeNotFound {
+            name: name.to_string(),
+            candidates: git::list_worktree_names(&worktrees_dir).unwrap_or_default(),
+        }
+        .into());
+    }
+
+    // The working-tree wipe is destructive, so guard it behind --force the
+    // same way `wt remove`/`wt exit` guard theirs. Unstaging alone can't
+    // lose work, so it's exempt.
+    if !staged_only && !force && git::has_uncommitted_changes(&path)? {
+        return Err(Error::UncommittedChanges.into());
+    }
+
+    gitbackend::reset_worktree(&path, staged_only)?;
+
+    if staged_only {
+        eprintln!("{} Unstaged changes in '{}'", "✓".green(), name.cyan());
+    } else {
+        eprintln!(
+            "{} Reset '{}' to branch HEAD, discarding all changes",
+            "✓".green(),
+            name.cyan()
+        );
+    }
+
+    Ok(())
+}
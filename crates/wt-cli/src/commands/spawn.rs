@@ -2,23 +2,120 @@
 
 use anyhow::Result;
 use colored::Colorize;
+use std::collections::HashMap;
+
+use wt_core::{config, git, spawn, template, terminal, vcs, Error};
+
+const DEFAULT_SPAWN_PROMPT: &str =
+    "Read .wt/task.md and begin work on the task.\n\n{{ context }}";
+
+/// Render the spawn prompt template for a worker, substituting the standard
+/// `{{ name }}`, `{{ branch }}`, `{{ issue }}`, `{{ context }}`, `{{ parent }}`,
+/// and `{{ date }}` variables.
+fn render_spawn_prompt(
+    repo_root: &std::path::Path,
+    name: &str,
+    branch: &str,
+    context: &str,
+) -> String {
+    let tmpl = template::load_or_default(repo_root, "spawn-prompt", DEFAULT_SPAWN_PROMPT);
+
+    let mut vars: HashMap<&str, String> = HashMap::new();
+    vars.insert("name", name.to_string());
+    vars.insert("branch", branch.to_string());
+    vars.insert("context", context.to_string());
+    vars.insert("issue", String::new());
+    vars.insert("parent", String::new());
+    vars.insert("date", chrono::Local::now().format("%Y-%m-%d").to_string());
+
+    template::render(&tmpl, &vars)
+}
 
-use wt_core::{config, git, spawn, terminal, Error};
-
-pub fn run(name: &str, context: Option<&str>, auto: bool) -> Result<()> {
+pub fn run(
+    name: &str,
+    context: Option<&str>,
+    auto: bool,
+    adapter: Option<&str>,
+    repos: Option<&str>,
+    exclude_repos: Option<&str>,
+    force: bool,
+) -> Result<()> {
     // Check for tmux
     if !terminal::command_exists("tmux") {
         return Err(Error::MissingDependency("tmux".to_string()).into());
     }
 
-    // Check for claude
-    if !terminal::command_exists("claude") {
-        return Err(Error::MissingDependency("claude".to_string()).into());
+    spawn_in_current_repo(name, context, auto, adapter, force)?;
+
+    // A polyrepo spawn is opt-in via --repos; without it, a plain `wt spawn`
+    // behaves exactly as it always has.
+    if let Some(pattern) = repos {
+        let wt_toml = config::read_layered_wt_toml(None)?;
+        let targets = spawn::select_projects(&wt_toml, Some(pattern), exclude_repos);
+
+        if targets.is_empty() {
+            eprintln!(
+                "  {} No [projects] match '{}'",
+                "→".dimmed(),
+                pattern
+            );
+        }
+
+        for project_name in targets {
+            let project = &wt_toml.projects[&project_name];
+            eprintln!();
+            eprintln!("{} {}", "▸".cyan(), project_name.bold());
+
+            let project_path = match spawn::ensure_project_repo(&project_name, project) {
+                Ok(path) => path,
+                Err(e) => {
+                    eprintln!("  {} Could not clone '{}': {}", "✗".red(), project_name, e);
+                    continue;
+                }
+            };
+
+            let result = spawn::with_repo_cwd(&project_path, || {
+                spawn_in_current_repo(name, context, auto, adapter, force)
+            });
+            if let Err(e) = result {
+                eprintln!("  {} Spawn in '{}' failed: {}", "✗".red(), project_name, e);
+            }
+        }
     }
 
+    Ok(())
+}
+
+/// Create (if needed) and spawn `name` in whatever repo the process is
+/// currently running in. Shared by the base repo and, via
+/// [`spawn::with_repo_cwd`], every `--repos`-selected project.
+fn spawn_in_current_repo(
+    name: &str,
+    context: Option<&str>,
+    auto: bool,
+    adapter: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    // Fail cleanly if this name already has a live task or tmux window,
+    // rather than silently clobbering spawn state underneath it — unless
+    // --force asked us to kill the stale window and take the name over.
+    spawn::check_no_collision(name, force)?;
+
     let worktrees_dir = git::get_worktrees_dir()?;
     let worktree_path = worktrees_dir.join(name);
 
+    // Resolve the effective config for this worktree: global < repo <
+    // worktree (a worktree's own `.wt/config.toml`, if it already exists,
+    // can pin its own adapter/hooks/services without touching the shared
+    // repo wt.toml).
+    let wt_toml = config::read_layered_wt_toml(Some(&worktree_path))?;
+    let agent = wt_toml.adapter(adapter);
+
+    // Check the adapter's command is on PATH
+    if !agent.is_on_path() {
+        return Err(Error::MissingDependency(agent.command().to_string()).into());
+    }
+
     // Check if worktree already exists
     let needs_create = !worktree_path.exists();
 
@@ -26,6 +123,7 @@ pub fn run(name: &str, context: Option<&str>, auto: bool) -> Result<()> {
         // Create worktree from current branch
         let current_branch = git::get_current_branch()?;
         let base_branch = config::get_base_branch()?;
+        let is_new_branch = !git::branch_exists(name)?;
 
         git::ensure_worktrees_excluded_auto()?;
 
@@ -34,8 +132,24 @@ pub fn run(name: &str, context: Option<&str>, auto: bool) -> Result<()> {
             std::fs::create_dir_all(parent)?;
         }
 
-        // Create new branch from current position
-        git::create_worktree(&worktree_path, name, &base_branch)?;
+        // Create new branch from current position, through the configured
+        // backend (git or jj) so spawning works unchanged on either.
+        let repo_root = git::get_base_repo()?;
+        let backend = vcs::detect_backend(&repo_root, &worktrees_dir);
+        backend.create_worktree(name, name, &base_branch)?;
+
+        // Materialize any configured scaffold files (e.g. a per-worktree
+        // `.env`) before anything else touches the worktree.
+        if !wt_toml.scaffold.is_empty() {
+            config::materialize_scaffold(
+                &repo_root,
+                &worktree_path,
+                name,
+                name,
+                &base_branch,
+                &wt_toml.scaffold,
+            )?;
+        }
 
         eprintln!(
             "{} Created worktree '{}' from '{}'",
@@ -43,28 +157,60 @@ pub fn run(name: &str, context: Option<&str>, auto: bool) -> Result<()> {
             name.cyan(),
             current_branch.cyan()
         );
+
+        // Wire up upstream tracking for newly created branches, if enabled.
+        if is_new_branch && wt_toml.tracking.auto_upstream {
+            let remote = wt_toml.tracking.remote_or_default();
+            let prefix = wt_toml.tracking.branch_prefix.as_deref();
+            if let Err(e) = git::set_upstream_tracking(&worktree_path, name, remote, prefix) {
+                eprintln!("  {} Could not set up upstream tracking: {}", "→".dimmed(), e);
+            }
+        }
     }
 
     // Determine if auto mode should be used
-    let use_auto = if auto {
-        true
-    } else {
-        // Check wt.toml for spawn.auto setting
-        config::read_wt_toml()?
-            .map(|c| c.spawn.auto)
-            .unwrap_or(false)
-    };
+    let use_auto = auto || wt_toml.spawn.auto;
 
     // Register in spawn state
     let branch = git::get_worktree_branch(&worktree_path)?;
     spawn::register(name, &branch, context)?;
 
+    // Render the spawn prompt template (if the caller gave us context to work with)
+    let repo_root = git::get_base_repo()?;
+    let rendered_prompt =
+        context.map(|ctx| render_spawn_prompt(&repo_root, name, &branch, ctx));
+
     // Launch in tmux
-    spawn::launch_tmux_window(name, &worktree_path, use_auto, context)?;
+    spawn::launch_tmux_window(
+        name,
+        &worktree_path,
+        use_auto,
+        rendered_prompt.as_deref(),
+        agent.as_ref(),
+    )?;
+
+    // Run the on_spawn hook, then launch any configured background services
+    if let Some(hook) = &wt_toml.hooks.on_spawn {
+        let mut vars = HashMap::new();
+        vars.insert("name", name.to_string());
+        vars.insert("branch", branch.clone());
+        config::run_hook(hook, &worktree_path, &vars)?;
+    }
+
+    if !wt_toml.services.is_empty() {
+        let pids = spawn::launch_services(&worktree_path, &wt_toml.services)?;
+        spawn::set_service_pids(name, pids)?;
+        eprintln!(
+            "  {} Started {} background service(s)",
+            "→".dimmed(),
+            wt_toml.services.len()
+        );
+    }
 
     eprintln!(
-        "{} Launched Claude in tmux window '{}'",
+        "{} Launched {} in tmux window '{}'",
         "✓".green(),
+        agent.name().cyan(),
         name.cyan()
     );
 
@@ -52,6 +52,27 @@ fn show_worker_status(state: &OrchestratorState, name: &str) -> Result<()> {
         }
     }
 
+    if worker.worktree_path.exists() {
+        if let Ok(entries) = git::get_status(&worker.worktree_path) {
+            eprintln!("  {} {}", "Files:".dimmed(), git::summarize_status(&entries));
+        }
+
+        if let Ok(Some((ahead, behind))) = git::get_upstream_ahead_behind(&worker.worktree_path) {
+            eprintln!("  {} {}", "Upstream:".dimmed(), format_upstream(ahead, behind));
+        }
+
+        if let Ok(commit) = git::get_last_commit(&worker.worktree_path) {
+            eprintln!(
+                "  {} {} {} ({}, {})",
+                "Last commit:".dimmed(),
+                commit.short_sha.yellow(),
+                commit.summary,
+                commit.author,
+                commit.relative_time
+            );
+        }
+    }
+
     if let WorkerStatus::WaitingReview { diff_stats } = &worker.status {
         eprintln!();
         eprintln!("  {}", "Changes:".bold());
@@ -61,6 +82,19 @@ fn show_worker_status(state: &OrchestratorState, name: &str) -> Result<()> {
             diff_stats.insertions.to_string().green(),
             diff_stats.deletions.to_string().red()
         );
+        if let Ok(entries) = git::get_status(&worker.worktree_path) {
+            eprintln!("    {}", git::summarize_status(&entries).dimmed());
+        }
+        for file in &diff_stats.files {
+            if let Some(error) = &file.error {
+                eprintln!(
+                    "    {} {} ({})",
+                    "!".yellow().bold(),
+                    file.path,
+                    error.reason().yellow()
+                );
+            }
+        }
     }
 
     eprintln!();
@@ -95,16 +129,43 @@ fn show_all_workers(state: &OrchestratorState) -> Result<()> {
     Ok(())
 }
 
+/// Render an upstream divergence as a gstat-style `↑2 ↓1` indicator.
+fn format_upstream(ahead: usize, behind: usize) -> String {
+    let mut parts = Vec::new();
+    if ahead > 0 {
+        parts.push(format!("↑{}", ahead).green().to_string());
+    }
+    if behind > 0 {
+        parts.push(format!("↓{}", behind).red().to_string());
+    }
+    if parts.is_empty() {
+        "up to date".dimmed().to_string()
+    } else {
+        parts.join(" ")
+    }
+}
+
 fn format_status(status: &WorkerStatus) -> String {
     match status {
         WorkerStatus::Spawned => "spawned".yellow().to_string(),
         WorkerStatus::Running => "running".blue().to_string(),
         WorkerStatus::WaitingReview { diff_stats } => {
-            format!(
-                "{} ({} files)",
-                "waiting review".magenta(),
-                diff_stats.files_changed
-            )
+            let error_count = diff_stats.files.iter().filter(|f| f.error.is_some()).count();
+            if error_count > 0 {
+                format!(
+                    "{} ({} files, {} {})",
+                    "waiting review".magenta(),
+                    diff_stats.files_changed,
+                    error_count,
+                    "!".yellow().bold()
+                )
+            } else {
+                format!(
+                    "{} ({} files)",
+                    "waiting review".magenta(),
+                    diff_stats.files_changed
+                )
+            }
         }
         WorkerStatus::Approved => "approved".green().to_string(),
         WorkerStatus::Merged => "merged".green().bold().to_string(),
@@ -0,0 +1,10 @@
+//! Switch command - select a worker's window without attaching a new client
+
+use anyhow::Result;
+
+use wt_core::spawn;
+
+pub fn run(name: Option<&str>) -> Result<()> {
+    spawn::switch(name)?;
+    Ok(())
+}
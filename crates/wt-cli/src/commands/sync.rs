@@ -0,0 +1,90 @@
+//! Sync command - rebase following worktrees onto their base ref
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::git::{self, RebaseOutcome};
+use wt_core::{config, Error};
+
+pub fn run(names: &[String]) -> Result<()> {
+    let worktrees_dir = git::get_worktrees_dir()?;
+
+    let targets: Vec<String> = if names.is_empty() {
+        git::list_worktrees()?
+    } else {
+        names.to_vec()
+    };
+
+    if targets.is_empty() {
+        eprintln!("No worktrees to sync");
+        return Ok(());
+    }
+
+    for name in targets {
+        let path = worktrees_dir.join(&name);
+        if !path.exists() {
+            if !names.is_empty() {
+                eprintln!(
+                    "{} {}",
+                    "✗".red(),
+                    Error::WorktreeNotFound {
+                        name: name.clone(),
+                        candidates: git::list_worktree_names(&worktrees_dir).unwrap_or_default(),
+                    }
+                );
+            }
+            continue;
+        }
+
+        let wt_toml = config::read_layered_wt_toml(Some(&path))?;
+        let Some(follow) = wt_toml.follow else {
+            // Only worktrees that opted in via `follow` are this command's
+            // business; silently skip everything else for a bare `wt sync`.
+            if !names.is_empty() {
+                eprintln!("  {} '{}' isn't following anything", "→".dimmed(), name.cyan());
+            }
+            continue;
+        };
+
+        if git::has_uncommitted_changes(&path)? {
+            eprintln!(
+                "  {} '{}' skipped — uncommitted changes, commit or stash first",
+                "→".dimmed(),
+                name.cyan()
+            );
+            continue;
+        }
+
+        let remote = follow.split('/').next().unwrap_or("origin");
+        if let Err(e) = git::fetch_remote(&path, remote) {
+            eprintln!("{} '{}' fetch of '{}' failed: {}", "✗".red(), name.cyan(), remote, e);
+            continue;
+        }
+
+        match git::rebase_onto(&path, &follow)? {
+            Ok(RebaseOutcome::UpToDate) => {
+                eprintln!("{} '{}' already up to date with '{}'", "✓".green(), name.cyan(), follow);
+            }
+            Ok(RebaseOutcome::Rebased(n)) => {
+                eprintln!(
+                    "{} '{}' rebased {} commit(s) onto '{}'",
+                    "✓".green(),
+                    name.cyan(),
+                    n,
+                    follow
+                );
+            }
+            Err(_conflict) => {
+                eprintln!(
+                    "{} '{}' conflicted rebasing onto '{}' — resolve in {} and run `git rebase --continue`",
+                    "✗".red(),
+                    name.cyan(),
+                    follow,
+                    path.display()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
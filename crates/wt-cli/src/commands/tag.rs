@@ -0,0 +1,12 @@
+//! Tag command - label a worker for bulk --tag operations
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::spawn;
+
+pub fn run(name: &str, tag: &str) -> Result<()> {
+    spawn::tag(name, tag)?;
+    eprintln!("{} Tagged '{}' with '{}'", "✓".green(), name.cyan(), tag.cyan());
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Undo command - revert the last recorded state mutation, or a specific
+//! one by id
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::{git, vcs, Error, OpKind, OpLog};
+
+pub fn run(id: Option<u64>) -> Result<()> {
+    let repo_root = git::get_base_repo()?;
+
+    let entry = match id {
+        Some(id) => OpLog::remove_by_id(&repo_root, id)?
+            .ok_or_else(|| Error::Custom(format!("no op-log entry #{}", id)))?,
+        None => OpLog::pop(&repo_root)?.ok_or_else(|| Error::Custom("nothing to undo".to_string()))?,
+    };
+
+    // A remove actually deleted the worktree on disk; a kill just tore down
+    // the tmux window and archived the state entry, so there's nothing to
+    // re-add there. Re-create it at its recorded branch before restoring the
+    // snapshot, so the worktree and the state entry don't drift apart again.
+    if entry.kind == OpKind::Remove {
+        if let (Some(path), Some(branch), Some(base_branch)) =
+            (&entry.worktree_path, &entry.branch, &entry.base_branch)
+        {
+            if !branch.is_empty() && !path.exists() {
+                let name = path.file_name().and_then(|n| n.to_str()).unwrap_or(branch);
+                let worktrees_dir = path.parent().unwrap_or(&repo_root);
+                let backend = vcs::detect_backend(&repo_root, worktrees_dir);
+                backend.create_worktree(name, branch, base_branch)?;
+                eprintln!(
+                    "  {} Re-created worktree at '{}' on branch '{}'",
+                    "→".dimmed(),
+                    path.display(),
+                    branch.cyan()
+                );
+            }
+        }
+    }
+
+    entry.snapshot.save()?;
+
+    eprintln!(
+        "{} Undid {} '{}'",
+        "✓".green(),
+        format!("#{}", entry.id).dimmed(),
+        entry.description.cyan()
+    );
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! Unlock command - remove a worktree's lock
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::{git, Error};
+
+pub fn run(name: &str) -> Result<()> {
+    let worktrees_dir = git::get_worktrees_dir()?;
+    let path = worktrees_dir.join(name);
+
+    if !path.exists() {
+        return Err(Error::WorktreeNotFound {
+            name: name.to_string(),
+            candidates: git::list_worktree_names(&worktrees_dir).unwrap_or_default(),
+        }
+        .into());
+    }
+
+    git::unlock_worktree(&path)?;
+
+    eprintln!("{} Unlocked worktree '{}'", "✓".green(), name.cyan());
+    Ok(())
+}
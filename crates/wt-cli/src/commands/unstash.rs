@@ -0,0 +1,37 @@
+//! Unstash command - re-apply a stash saved by `wt remove --stash`/`wt exit --stash`
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::{config, git, gitbackend, Error, Worktree};
+
+pub fn run(name: &str) -> Result<()> {
+    let repo_root = git::get_base_repo()?;
+    let worktrees_dir = git::get_worktrees_dir()?;
+
+    let Some((index, branch)) = gitbackend::find_stash(&repo_root, name)? else {
+        return Err(Error::StashNotFound(name.to_string()).into());
+    };
+
+    let path = worktrees_dir.join(name);
+    if !path.exists() {
+        eprintln!(
+            "  {} Worktree '{}' is gone; recreating it on branch '{}'",
+            "→".dimmed(),
+            name.cyan(),
+            branch.cyan()
+        );
+        let base_branch = config::get_base_branch()?;
+        let git_common_dir = git::get_git_common_dir()?;
+        Worktree::create(&worktrees_dir, &git_common_dir, name, Some(&branch), &base_branch, None, &[])?;
+    }
+
+    gitbackend::stash_pop(&path, index)?;
+
+    eprintln!(
+        "{} Restored stashed changes onto '{}'",
+        "✓".green(),
+        name.cyan()
+    );
+    Ok(())
+}
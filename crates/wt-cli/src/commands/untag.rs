@@ -0,0 +1,12 @@
+//! Untag command - remove a tag from a worker
+
+use anyhow::Result;
+use colored::Colorize;
+
+use wt_core::spawn;
+
+pub fn run(name: &str, tag: &str) -> Result<()> {
+    spawn::untag(name, tag)?;
+    eprintln!("{} Untagged '{}' from '{}'", "✓".green(), name.cyan(), tag.cyan());
+    Ok(())
+}
@@ -0,0 +1,18 @@
+//! Workon command - print a `cd` line to jump into a registered project
+
+use anyhow::Result;
+
+use wt_core::config::Config;
+use wt_core::Error;
+
+pub fn run(name: &str) -> Result<()> {
+    let config = Config::load()?;
+    let project = config
+        .projects(None)
+        .into_iter()
+        .find(|p| p.name == name)
+        .ok_or_else(|| Error::ProjectNotFound(name.to_string()))?;
+
+    println!("cd '{}'", project.path.display());
+    Ok(())
+}
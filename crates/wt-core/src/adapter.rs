@@ -0,0 +1,121 @@
+//! Pluggable coding-agent adapters
+//!
+//! `wt` was written assuming Claude Code, but driving a coding agent from a
+//! freshly spawned worktree only needs three things: a launch command, a
+//! place for its skills/commands, and a way to tell whether it's installed.
+//! `Adapter` captures that seam — mirroring how [`crate::vcs::VcsBackend`]
+//! lets `wt` drive `jj` alongside `git` — so aider/codex/a custom agent can
+//! be added purely through an `[adapters.<name>]` table in wt.toml, with no
+//! change to `wt spawn`, `wt init`, or `wt health`.
+
+use std::path::Path;
+
+use crate::config::{resolve_adapter, AdapterConfig, PromptMode, WtToml};
+use crate::error::Result;
+
+/// Operations `wt` needs from a coding-agent adapter.
+pub trait Adapter {
+    /// Name this adapter was resolved under: the `[adapters.<name>]` key,
+    /// or the built-in default's name.
+    fn name(&self) -> &str;
+
+    /// Launch command, e.g. "claude" or "aider" — what [`Adapter::is_on_path`]
+    /// checks for and what `wt spawn` execs in the new tmux window.
+    fn command(&self) -> &str;
+
+    /// Directory (relative to a worktree root) this adapter's skills/
+    /// commands live in, for `wt init` to scaffold. `None` if this adapter
+    /// has no per-project skills layout.
+    fn skills_dir(&self) -> Option<&str>;
+
+    /// Build the shell command used to launch this adapter in a freshly
+    /// created tmux window: wires `context` in per the adapter's prompt
+    /// mode, and appends its `auto_flags` when spawning unattended.
+    fn build_command(&self, context: Option<&str>, auto: bool, worktree_path: &Path) -> Result<String>;
+
+    /// Whether `command()` resolves on `PATH`, for `wt health`.
+    fn is_on_path(&self) -> bool {
+        crate::terminal::command_exists(self.command())
+    }
+}
+
+/// An adapter driven purely by `[adapters.<name>]` config data, including
+/// the built-in `claude` default. Every adapter today differs only in
+/// command/prompt-mode/flags/skills-dir, so one impl covers all of them
+/// rather than a bespoke type per agent — new backends (`jj`, by contrast,
+/// needed its own [`VcsBackend`](crate::vcs::VcsBackend) impl because its
+/// operations genuinely behave differently, not just by name).
+pub struct ConfiguredAdapter {
+    name: String,
+    config: AdapterConfig,
+}
+
+impl ConfiguredAdapter {
+    pub fn new(name: impl Into<String>, config: AdapterConfig) -> Self {
+        Self {
+            name: name.into(),
+            config,
+        }
+    }
+
+    /// The built-in `claude` adapter, used when wt.toml has no
+    /// `[adapters]` section.
+    pub fn claude_default() -> Self {
+        Self::new("claude", AdapterConfig::claude_default())
+    }
+}
+
+impl Adapter for ConfiguredAdapter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn command(&self) -> &str {
+        &self.config.command
+    }
+
+    fn skills_dir(&self) -> Option<&str> {
+        self.config.skills_dir.as_deref()
+    }
+
+    fn build_command(&self, context: Option<&str>, auto: bool, worktree_path: &Path) -> Result<String> {
+        let mut cmd = self.config.command.clone();
+
+        if let Some(ctx) = context {
+            match self.config.prompt_mode {
+                PromptMode::PromptArg => {
+                    let escaped = ctx.replace('\'', "'\\''");
+                    cmd = format!("{} '{}'", cmd, escaped);
+                }
+                PromptMode::PromptFile => {
+                    let prompt_path = worktree_path.join(".wt-prompt.md");
+                    std::fs::write(&prompt_path, ctx)?;
+                    cmd = format!("{} --prompt-file '{}'", cmd, prompt_path.display());
+                }
+                PromptMode::Stdin => {
+                    let escaped = ctx.replace('\'', "'\\''");
+                    cmd = format!("echo '{}' | {}", escaped, cmd);
+                }
+            }
+        }
+
+        if auto {
+            for flag in &self.config.auto_flags {
+                cmd.push(' ');
+                cmd.push_str(flag);
+            }
+        }
+
+        Ok(cmd)
+    }
+}
+
+/// Resolve the [`Adapter`] to use: an explicit `name`, falling back to
+/// `spawn.default_adapter` in `toml`, falling back to the built-in `claude`
+/// adapter. The registry is implicit — every `[adapters.*]` entry in
+/// `wt.toml` is reachable by name, plus `claude` is always available even
+/// with no config, so resolution never fails.
+pub fn resolve(toml: Option<&WtToml>, name: Option<&str>) -> Box<dyn Adapter> {
+    let (resolved_name, config) = resolve_adapter(toml, name);
+    Box::new(ConfiguredAdapter::new(resolved_name, config))
+}
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use crate::error::{Error, Result};
 
@@ -63,6 +64,12 @@ impl Config {
         Ok(Self::config_dir()?.join("config"))
     }
 
+    /// Managed clone location for a `[projects.<name>]` repo, under the
+    /// config directory so it survives outside of any one repo's worktrees.
+    pub fn managed_project_dir(name: &str) -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("repos").join(name))
+    }
+
     /// Load config from disk
     pub fn load() -> Result<Self> {
         let config_file = Self::config_file()?;
@@ -170,19 +177,34 @@ impl Config {
 
     /// Get on-create hook for repo
     pub fn get_on_create_hook(&self, repo_path: &Path) -> Option<String> {
-        let key = format!("{}:on_create", repo_path.to_string_lossy());
-        self.entries.get(&key).cloned()
+        self.get_hook(repo_path, HookEvent::OnCreate)
     }
 
     /// Set on-create hook for repo
     pub fn set_on_create_hook(&mut self, repo_path: &Path, command: &str) {
-        let key = format!("{}:on_create", repo_path.to_string_lossy());
-        self.set(key, command.to_string());
+        self.set_hook(repo_path, HookEvent::OnCreate, command);
     }
 
     /// Unset on-create hook for repo
     pub fn unset_on_create_hook(&mut self, repo_path: &Path) {
-        let key = format!("{}:on_create", repo_path.to_string_lossy());
+        self.unset_hook(repo_path, HookEvent::OnCreate);
+    }
+
+    /// Get the hook command configured for `event` on `repo_path`, if any.
+    pub fn get_hook(&self, repo_path: &Path, event: HookEvent) -> Option<String> {
+        let key = format!("{}:{}", repo_path.to_string_lossy(), event.as_key());
+        self.entries.get(&key).cloned()
+    }
+
+    /// Set the hook command for `event` on `repo_path`.
+    pub fn set_hook(&mut self, repo_path: &Path, event: HookEvent, command: &str) {
+        let key = format!("{}:{}", repo_path.to_string_lossy(), event.as_key());
+        self.set(key, command.to_string());
+    }
+
+    /// Remove the hook command for `event` on `repo_path`.
+    pub fn unset_hook(&mut self, repo_path: &Path, event: HookEvent) {
+        let key = format!("{}:{}", repo_path.to_string_lossy(), event.as_key());
         self.remove(&key);
     }
 
@@ -191,21 +213,17 @@ impl Config {
         let mut entries = Vec::new();
 
         for (key, value) in &self.entries {
-            let category = if key == "_default" {
-                "[global]".to_string()
-            } else if key.contains(":on_create") {
-                let path = key.strip_suffix(":on_create").unwrap_or(key);
-                format!("[{}] on-create", path)
-            } else {
-                format!("[{}]", key)
-            };
-
-            let display_key = if key == "_default" {
-                "base".to_string()
-            } else if key.contains(":on_create") {
-                "on-create".to_string()
+            let hook_event = HookEvent::ALL
+                .iter()
+                .find(|event| key.ends_with(&format!(":{}", event.as_key())));
+
+            let (category, display_key) = if key == "_default" {
+                ("[global]".to_string(), "base".to_string())
+            } else if let Some(event) = hook_event {
+                let path = key.strip_suffix(&format!(":{}", event.as_key())).unwrap_or(key);
+                (format!("[{}] {}", path, event.as_flag()), event.as_flag().to_string())
             } else {
-                "base".to_string()
+                (format!("[{}]", key), "base".to_string())
             };
 
             entries.push((category, display_key, value.clone()));
@@ -213,6 +231,114 @@ impl Config {
 
         entries
     }
+
+    /// Record `repo_path` as a project `wt` manages, keyed by its absolute
+    /// path, so `wt projects`/`wt workon` can list and jump to it from
+    /// anywhere. Called by `wt init`; re-running it (e.g. `wt init --force`)
+    /// overwrites the name/tags rather than duplicating the entry.
+    pub fn register_project(&mut self, repo_path: &Path, name: &str, tags: &[String]) {
+        let key = repo_path.to_string_lossy().to_string();
+        self.set(format!("project:{}", key), name.to_string());
+
+        let tags_key = format!("project:{}:tags", key);
+        if tags.is_empty() {
+            self.remove(&tags_key);
+        } else {
+            self.set(tags_key, tags.join(","));
+        }
+    }
+
+    /// Every registered project, optionally narrowed to those carrying
+    /// `tag`, sorted by display name for stable `wt projects` output.
+    pub fn projects(&self, tag: Option<&str>) -> Vec<ProjectEntry> {
+        let mut projects: Vec<ProjectEntry> = self
+            .entries
+            .iter()
+            .filter_map(|(key, name)| {
+                let path = key.strip_prefix("project:")?;
+                if path.ends_with(":tags") {
+                    return None;
+                }
+
+                let tags = self
+                    .entries
+                    .get(&format!("project:{}:tags", path))
+                    .map(|raw| raw.split(',').map(str::to_string).collect())
+                    .unwrap_or_default();
+
+                Some(ProjectEntry {
+                    path: PathBuf::from(path),
+                    name: name.clone(),
+                    tags,
+                })
+            })
+            .filter(|project| match tag {
+                Some(tag) => project.tags.iter().any(|t| t == tag),
+                None => true,
+            })
+            .collect();
+
+        projects.sort_by(|a, b| a.name.cmp(&b.name));
+        projects
+    }
+}
+
+/// A repo registered with `wt` via [`Config::register_project`], for `wt
+/// projects` to list and `wt workon` to jump to.
+#[derive(Debug, Clone)]
+pub struct ProjectEntry {
+    pub path: PathBuf,
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+/// A lifecycle event a hook can be attached to, mirroring how `base` is
+/// scoped per-repo in [`Config`]. Each variant is settable/unsettable the
+/// same way as the base branch (`wt config <event> <command>` / `--unset`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    /// Runs after a new worktree is created.
+    OnCreate,
+    /// Runs after a worktree is removed.
+    OnRemove,
+    /// Runs when exiting (and removing) the current worktree.
+    OnExit,
+    /// Runs before a merge; a non-zero exit aborts the merge.
+    PreMerge,
+    /// Runs after a successful merge.
+    PostMerge,
+}
+
+impl HookEvent {
+    pub const ALL: [HookEvent; 5] = [
+        HookEvent::OnCreate,
+        HookEvent::OnRemove,
+        HookEvent::OnExit,
+        HookEvent::PreMerge,
+        HookEvent::PostMerge,
+    ];
+
+    /// Key suffix used when storing this event's hook in [`Config`], e.g. `on_create`.
+    pub fn as_key(&self) -> &'static str {
+        match self {
+            HookEvent::OnCreate => "on_create",
+            HookEvent::OnRemove => "on_remove",
+            HookEvent::OnExit => "on_exit",
+            HookEvent::PreMerge => "pre_merge",
+            HookEvent::PostMerge => "post_merge",
+        }
+    }
+
+    /// `wt config` flag spelling of this event, e.g. `on-create`.
+    pub fn as_flag(&self) -> &'static str {
+        match self {
+            HookEvent::OnCreate => "on-create",
+            HookEvent::OnRemove => "on-remove",
+            HookEvent::OnExit => "on-exit",
+            HookEvent::PreMerge => "pre-merge",
+            HookEvent::PostMerge => "post-merge",
+        }
+    }
 }
 
 /// Run on-create hook in a directory
@@ -235,11 +361,324 @@ pub fn run_on_create_hook(hook: &str, dir: &Path) -> Result<bool> {
     Ok(true)
 }
 
+/// Run a lifecycle hook command with the worktree path as its working
+/// directory and `vars` exposed as `WT_<KEY>` environment variables.
+pub fn run_hook(hook: &str, worktree_path: &Path, vars: &HashMap<&str, String>) -> Result<bool> {
+    tracing::info!("Running hook: {}", hook);
+
+    let mut command = std::process::Command::new("sh");
+    command.args(["-c", hook]).current_dir(worktree_path);
+
+    for (key, value) in vars {
+        command.env(format!("WT_{}", key.to_uppercase()), value);
+    }
+
+    let output = command.output()?;
+
+    if !output.status.success() {
+        tracing::warn!(
+            "hook failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
+/// Run a `wt config`-managed lifecycle hook (see [`HookEvent`]), following
+/// the pattern git itself uses for its own hooks: a stable set of `WT_*`
+/// environment variables plus the worktree name as `$1`.
+pub fn run_lifecycle_hook(
+    hook: &str,
+    event: HookEvent,
+    worktree_path: &Path,
+    name: &str,
+    branch: &str,
+    base_branch: &str,
+) -> Result<bool> {
+    tracing::info!("Running {} hook: {}", event.as_flag(), hook);
+
+    let output = std::process::Command::new("sh")
+        .arg("-c")
+        .arg(hook)
+        .arg("sh") // becomes $0, so `name` below lands at $1
+        .arg(name)
+        .current_dir(worktree_path)
+        .env("WT_NAME", name)
+        .env("WT_PATH", worktree_path.to_string_lossy().to_string())
+        .env("WT_BRANCH", branch)
+        .env("WT_BASE", base_branch)
+        .env("WT_EVENT", event.as_key())
+        .output()?;
+
+    if !output.status.success() {
+        tracing::warn!(
+            "{} hook failed: {}",
+            event.as_flag(),
+            String::from_utf8_lossy(&output.stderr)
+        );
+        return Ok(false);
+    }
+
+    Ok(true)
+}
+
 /// WtToml configuration from wt.toml
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct WtToml {
+    /// Default base branch for new worktrees. Overridable per-layer.
+    #[serde(default)]
+    pub base_branch: Option<String>,
+    /// Directory worktrees are created under, relative to the repo root
+    /// (e.g. `".worktrees"`). Overridable per-layer like `base_branch`.
+    #[serde(default)]
+    pub worktree_dir: Option<String>,
+    /// Whether a freshly spawned worker starts in `WaitingReview` once its
+    /// adapter process exits, instead of requiring an explicit `wt review`.
+    #[serde(default)]
+    pub auto_review: Option<bool>,
     #[serde(default)]
     pub spawn: SpawnConfig,
+    /// Version control backend to use ("git" or "jj"). Auto-detected when unset.
+    #[serde(default)]
+    pub vcs: Option<String>,
+    /// Named coding-agent adapters, keyed by name (e.g. `claude`, `aider`).
+    #[serde(default)]
+    pub adapters: HashMap<String, AdapterConfig>,
+    /// Lifecycle hooks run at each stage of a worktree's life.
+    #[serde(default)]
+    pub hooks: HooksConfig,
+    /// Background services launched alongside a spawned worktree
+    /// (e.g. `["npm run dev", "cargo watch"]`), torn down on remove/exit/kill.
+    #[serde(default)]
+    pub services: Vec<String>,
+    /// Upstream tracking setup for newly created worktree branches.
+    #[serde(default)]
+    pub tracking: TrackingConfig,
+    /// Branches that must never be deleted by `wt`, even with `--force`
+    /// (e.g. `["main", "develop"]`).
+    #[serde(default)]
+    pub persistent_branches: Vec<String>,
+    /// Force all git operations through the `git` CLI instead of `libgit2`,
+    /// for environments where the linked libgit2 lacks a feature `wt` needs
+    /// (e.g. a credential helper or transport).
+    #[serde(default)]
+    pub prefer_shell_git: bool,
+    /// Stash dirty changes instead of hard-failing on `wt exit`/`wt remove`,
+    /// as if `--stash` were always passed.
+    #[serde(default)]
+    pub stash_on_remove: bool,
+    /// Other repositories `wt spawn --repos` can target alongside this one,
+    /// keyed by a short name (e.g. `"api"`, `"docs"`). Turns the spawn
+    /// subsystem into a monorepo/polyrepo orchestration layer: a repo listed
+    /// here is cloned into a managed location on first use, then gets its
+    /// own worktree and tmux window per spawn.
+    #[serde(default)]
+    pub projects: HashMap<String, RemoteRepo>,
+    /// User-defined command shortcuts, e.g. `r = "review --full"`, keyed by
+    /// the alias name under `[alias]`. Resolved by the CLI dispatch layer
+    /// before subcommand matching; see [`crate::alias`] for expansion rules.
+    #[serde(default)]
+    pub alias: HashMap<String, AliasValue>,
+    /// Files materialized into every new worktree at creation time, under
+    /// `[[scaffold]]`. See [`ScaffoldFile`].
+    #[serde(default)]
+    pub scaffold: Vec<ScaffoldFile>,
+    /// A ref (e.g. `"origin/main"`) this worktree's branch should stay
+    /// rebased onto, set in the worktree's own `.wt/config.toml`. Consumed
+    /// by `wt sync`.
+    #[serde(default)]
+    pub follow: Option<String>,
+    /// Template variables available to `wt init`'s templates, under
+    /// `[init.vars]`.
+    #[serde(default)]
+    pub init: InitConfig,
+    /// User-supplied terminal launch template, under `[terminal]`, for
+    /// emulators `wt`'s built-in `Terminal` detection doesn't know about.
+    #[serde(default)]
+    pub terminal: Option<TerminalConfig>,
+    /// SSH host this worktree's worker runs on, under `[remote]`. Worktree
+    /// and branch bookkeeping still happens locally; only the tmux session
+    /// and the terminal `wt` opens for it are driven remotely. See
+    /// [`RemoteConfig`].
+    #[serde(default)]
+    pub remote: Option<RemoteConfig>,
+}
+
+/// A file to materialize into a new worktree at creation time, with
+/// `{{ name }}`/`{{ branch }}`/`{{ base }}`/`{{ path }}` substituted via
+/// [`crate::template::render`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaffoldFile {
+    /// Source template under `<repo_root>/templates/`, e.g. `"env.example"`.
+    pub template: String,
+    /// Destination path relative to the new worktree, e.g. `".env"`.
+    pub dest: String,
+}
+
+/// An `[alias]` entry's value: either a whitespace-split string (cargo's
+/// `r = "review --full"` form) or an explicit argument list (`r = ["review",
+/// "--full"]`), for arguments that themselves contain whitespace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AliasValue {
+    Line(String),
+    Args(Vec<String>),
+}
+
+impl AliasValue {
+    /// Expand into the argument vector it substitutes in place of the alias.
+    pub fn into_args(self) -> Vec<String> {
+        match self {
+            AliasValue::Line(s) => s.split_whitespace().map(str::to_string).collect(),
+            AliasValue::Args(args) => args,
+        }
+    }
+}
+
+/// A repository `wt spawn --repos` can materialize and spawn into, declared
+/// under `[projects.<name>]` in wt.toml.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteRepo {
+    /// Remote URL to clone from, e.g. `"git@github.com:org/api.git"`.
+    pub url: String,
+    /// Branch to clone and spawn from. Defaults to the remote's HEAD branch.
+    #[serde(default)]
+    pub branch: Option<String>,
+}
+
+/// Shell commands run at each stage of a worktree's lifecycle.
+///
+/// Each hook is run with the worktree path as its working directory and
+/// task variables (`WT_NAME`, `WT_BRANCH`, ...) set in its environment.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct HooksConfig {
+    #[serde(default)]
+    pub on_create: Option<String>,
+    #[serde(default)]
+    pub on_spawn: Option<String>,
+    #[serde(default)]
+    pub on_open: Option<String>,
+    #[serde(default)]
+    pub on_review: Option<String>,
+    #[serde(default)]
+    pub on_merge: Option<String>,
+    #[serde(default)]
+    pub on_remove: Option<String>,
+}
+
+impl HooksConfig {
+    /// Overlay `other`'s hooks onto `self`, field-by-field. Any hook `other`
+    /// sets takes precedence; hooks it leaves unset fall through to `self`.
+    fn overlay(&mut self, other: &HooksConfig) {
+        if other.on_create.is_some() {
+            self.on_create = other.on_create.clone();
+        }
+        if other.on_spawn.is_some() {
+            self.on_spawn = other.on_spawn.clone();
+        }
+        if other.on_open.is_some() {
+            self.on_open = other.on_open.clone();
+        }
+        if other.on_review.is_some() {
+            self.on_review = other.on_review.clone();
+        }
+        if other.on_merge.is_some() {
+            self.on_merge = other.on_merge.clone();
+        }
+        if other.on_remove.is_some() {
+            self.on_remove = other.on_remove.clone();
+        }
+    }
+}
+
+/// Upstream tracking setup applied to newly created worktree branches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackingConfig {
+    /// Remote to track against, e.g. `"origin"`.
+    #[serde(default)]
+    pub remote: Option<String>,
+    /// Prefix prepended to the local branch name to form the remote
+    /// tracking branch, e.g. a prefix of `"wip/"` for local branch `feature`
+    /// tracks `<remote>/wip/feature`.
+    #[serde(default)]
+    pub branch_prefix: Option<String>,
+    /// Whether to set up tracking automatically for newly created branches.
+    #[serde(default)]
+    pub auto_upstream: bool,
+}
+
+impl Default for TrackingConfig {
+    fn default() -> Self {
+        Self {
+            remote: None,
+            branch_prefix: None,
+            auto_upstream: false,
+        }
+    }
+}
+
+impl TrackingConfig {
+    /// Overlay `other`'s fields onto `self`, field-by-field.
+    fn overlay(&mut self, other: &TrackingConfig) {
+        if other.remote.is_some() {
+            self.remote = other.remote.clone();
+        }
+        if other.branch_prefix.is_some() {
+            self.branch_prefix = other.branch_prefix.clone();
+        }
+        if other.auto_upstream {
+            self.auto_upstream = true;
+        }
+    }
+
+    /// The remote to track against, defaulting to `"origin"`.
+    pub fn remote_or_default(&self) -> &str {
+        self.remote.as_deref().unwrap_or("origin")
+    }
+}
+
+/// `wt init` template configuration, under `[init]` in wt.toml.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct InitConfig {
+    /// Arbitrary `{{ key }}` substitutions made available to init templates
+    /// alongside the built-in `project_name`, e.g. `license = "MIT"`.
+    /// Overridable per-invocation with `wt init --var key=value`.
+    #[serde(default)]
+    pub vars: HashMap<String, String>,
+}
+
+/// A user-supplied terminal launch recipe, under `[terminal]` in wt.toml,
+/// for emulators `wt`'s built-in [`crate::terminal::Terminal`] detection
+/// doesn't recognize (foot, konsole, gnome-terminal, ...).
+/// [`crate::terminal::open_tab`] prefers this over auto-detection when present.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalConfig {
+    /// Launch command, e.g. `"kitten"`.
+    pub command: String,
+    /// Arguments, with a literal `{dir}` substituted for the worktree path,
+    /// e.g. `["@", "launch", "--type=tab", "--cwd", "{dir}"]`.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// A worktree's worker runs on another machine over SSH, under `[remote]` in
+/// wt.toml. `wt` still creates the worktree and branch locally (so `git
+/// worktree list`, state, and config stay in one place); only the tmux
+/// session the worker lives in, and the terminal `wt` opens to reach it, are
+/// remote. [`crate::spawn::attach`] and [`crate::terminal::open_tab`] detect
+/// this and wrap the launch in `ssh -t <host> 'tmux attach -t <session>'`
+/// (`-t` forces a PTY so tmux renders correctly over the link) instead of
+/// driving tmux/the terminal locally.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteConfig {
+    /// SSH host, exactly as you'd pass it to `ssh` (e.g. `"build-box"` or
+    /// `"user@1.2.3.4"`).
+    pub host: String,
+    /// Path to the repository on `host`, if it differs from the local path.
+    #[serde(default)]
+    pub repo_path: Option<String>,
 }
 
 /// Spawn configuration in wt.toml
@@ -247,19 +686,84 @@ pub struct WtToml {
 pub struct SpawnConfig {
     #[serde(default)]
     pub auto: bool,
+    /// Name of the adapter to use when `--adapter` isn't passed.
+    #[serde(default)]
+    pub default_adapter: Option<String>,
+}
+
+/// How an adapter expects to receive its prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PromptMode {
+    /// Write the prompt to a file and pass its path as an argument.
+    PromptFile,
+    /// Pass the prompt directly as a command-line argument.
+    PromptArg,
+    /// Write the prompt to the adapter's stdin.
+    Stdin,
+}
+
+impl Default for PromptMode {
+    fn default() -> Self {
+        PromptMode::PromptArg
+    }
+}
+
+/// A named coding-agent adapter, configured under `[adapters.<name>]` in wt.toml.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdapterConfig {
+    /// Launch command, e.g. "claude" or "aider".
+    pub command: String,
+    /// How the adapter receives its prompt.
+    #[serde(default)]
+    pub prompt_mode: PromptMode,
+    /// Extra flags appended when spawning in unattended/auto mode.
+    #[serde(default)]
+    pub auto_flags: Vec<String>,
+    /// Directory (relative to a worktree root) this adapter's skills/
+    /// commands live in, e.g. ".claude/commands". `None` means this adapter
+    /// has no per-project skills layout for `wt init` to scaffold.
+    #[serde(default)]
+    pub skills_dir: Option<String>,
+}
+
+impl AdapterConfig {
+    /// The built-in default adapter, used when wt.toml has no `[adapters]` section.
+    pub fn claude_default() -> Self {
+        Self {
+            command: "claude".to_string(),
+            prompt_mode: PromptMode::PromptArg,
+            auto_flags: vec!["--dangerously-skip-permissions".to_string()],
+            skills_dir: Some(".claude/commands".to_string()),
+        }
+    }
 }
 
 impl WtToml {
     /// Read wt.toml from a repository
     pub fn load(repo_root: &Path) -> Result<Option<Self>> {
-        let toml_path = repo_root.join("wt.toml");
+        Self::load_from(&repo_root.join("wt.toml"))
+    }
+
+    /// Read the user-global config layer from `~/.config/wt/config.toml`
+    /// (or `$XDG_CONFIG_HOME/wt/config.toml`).
+    pub fn load_global() -> Result<Option<Self>> {
+        Self::load_from(&Config::config_dir()?.join("config.toml"))
+    }
+
+    /// Read the per-worktree config layer from `<worktree>/.wt/config.toml`.
+    pub fn load_worktree(worktree_path: &Path) -> Result<Option<Self>> {
+        Self::load_from(&worktree_path.join(".wt").join("config.toml"))
+    }
 
+    fn load_from(toml_path: &Path) -> Result<Option<Self>> {
         if !toml_path.exists() {
             return Ok(None);
         }
 
-        let content = fs::read_to_string(&toml_path)?;
-        let config: WtToml = toml::from_str(&content)?;
+        let content = fs::read_to_string(toml_path)?;
+        let config: WtToml =
+            toml::from_str(&content).map_err(|e| crate::error::Error::toml_parse(e, content))?;
         Ok(Some(config))
     }
 
@@ -267,21 +771,313 @@ impl WtToml {
     pub fn exists(repo_root: &Path) -> bool {
         repo_root.join("wt.toml").exists()
     }
+
+    /// Overlay `other`'s fields onto `self`, in place, field-by-field. Any
+    /// value `other` sets wins; anything it leaves unset falls through to
+    /// `self`. Used to stack global → repo → worktree config layers.
+    pub fn overlay(&mut self, other: &WtToml) {
+        if other.base_branch.is_some() {
+            self.base_branch = other.base_branch.clone();
+        }
+        if other.worktree_dir.is_some() {
+            self.worktree_dir = other.worktree_dir.clone();
+        }
+        if other.auto_review.is_some() {
+            self.auto_review = other.auto_review;
+        }
+        if other.vcs.is_some() {
+            self.vcs = other.vcs.clone();
+        }
+        if other.spawn.default_adapter.is_some() {
+            self.spawn.default_adapter = other.spawn.default_adapter.clone();
+        }
+        if other.spawn.auto {
+            self.spawn.auto = other.spawn.auto;
+        }
+        for (name, adapter) in &other.adapters {
+            self.adapters.insert(name.clone(), adapter.clone());
+        }
+        self.hooks.overlay(&other.hooks);
+        if !other.services.is_empty() {
+            self.services = other.services.clone();
+        }
+        self.tracking.overlay(&other.tracking);
+        if !other.persistent_branches.is_empty() {
+            self.persistent_branches = other.persistent_branches.clone();
+        }
+        if other.prefer_shell_git {
+            self.prefer_shell_git = true;
+        }
+        if other.stash_on_remove {
+            self.stash_on_remove = true;
+        }
+        for (name, repo) in &other.projects {
+            self.projects.insert(name.clone(), repo.clone());
+        }
+        for (name, alias) in &other.alias {
+            self.alias.insert(name.clone(), alias.clone());
+        }
+        if !other.scaffold.is_empty() {
+            self.scaffold = other.scaffold.clone();
+        }
+        if other.follow.is_some() {
+            self.follow = other.follow.clone();
+        }
+        for (key, value) in &other.init.vars {
+            self.init.vars.insert(key.clone(), value.clone());
+        }
+        if other.remote.is_some() {
+            self.remote = other.remote.clone();
+        }
+    }
+}
+
+/// Materialize each `[[scaffold]]` file into `worktree_path`, expanding
+/// `{{ name }}`, `{{ branch }}`, `{{ base }}`, and `{{ path }}` against the
+/// worktree being created. Missing source templates are skipped with a
+/// warning rather than failing the whole worktree creation.
+pub fn materialize_scaffold(
+    repo_root: &Path,
+    worktree_path: &Path,
+    name: &str,
+    branch: &str,
+    base_branch: &str,
+    files: &[ScaffoldFile],
+) -> Result<()> {
+    let mut vars: HashMap<&str, String> = HashMap::new();
+    vars.insert("name", name.to_string());
+    vars.insert("branch", branch.to_string());
+    vars.insert("base", base_branch.to_string());
+    vars.insert("path", worktree_path.display().to_string());
+
+    for file in files {
+        let source_path = repo_root.join("templates").join(&file.template);
+        let Ok(source) = fs::read_to_string(&source_path) else {
+            eprintln!(
+                "  warning: scaffold template '{}' not found, skipping",
+                source_path.display()
+            );
+            continue;
+        };
+
+        let rendered = crate::template::render(&source, &vars);
+        let dest_path = worktree_path.join(&file.dest);
+        if let Some(parent) = dest_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(dest_path, rendered)?;
+    }
+
+    Ok(())
+}
+
+/// Which config layer an effective value came from, in precedence order
+/// (later layers override earlier ones).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigLayer {
+    /// Built-in default; no layer set this value.
+    Default,
+    /// `~/.config/wt/config.toml`
+    Global,
+    /// `<repo>/wt.toml`
+    Repo,
+    /// `<worktree>/.wt/config.toml`
+    Worktree,
+    /// A `WT_*` environment variable.
+    Env,
+    /// A repeated `--config key=value` CLI flag, the highest-precedence
+    /// layer — it's meant to override everything else for one invocation.
+    Cli,
+}
+
+impl ConfigLayer {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfigLayer::Default => "default",
+            ConfigLayer::Global => "global",
+            ConfigLayer::Repo => "repo",
+            ConfigLayer::Worktree => "worktree",
+            ConfigLayer::Env => "env",
+            ConfigLayer::Cli => "cli",
+        }
+    }
+}
+
+/// `--config key=value` overrides parsed from argv, installed once (via
+/// [`set_cli_config_overrides`]) before any config is resolved. Recognized
+/// keys: `base_branch`/`repo.base_branch`, `agent.type`/
+/// `spawn.default_adapter`, and `hooks.on_create`.
+static CLI_CONFIG_OVERRIDES: OnceLock<HashMap<String, String>> = OnceLock::new();
+
+/// Install `--config key=value` overrides from the CLI as the
+/// highest-precedence layer in [`LayeredConfig`]'s resolution stack — above
+/// even `WT_*` environment variables, since a flag passed on the command
+/// line is scoped to one invocation while an env var can linger in a shell
+/// session. Call once, right after `Cli::parse`, before any command runs;
+/// later calls are no-ops (an `OnceLock` only ever accepts its first write,
+/// and `wt` only parses argv once per process anyway).
+pub fn set_cli_config_overrides(pairs: &[String]) {
+    let map: HashMap<String, String> = pairs
+        .iter()
+        .filter_map(|pair| pair.split_once('=').map(|(k, v)| (k.to_string(), v.to_string())))
+        .collect();
+    let _ = CLI_CONFIG_OVERRIDES.set(map);
+}
+
+fn cli_override(keys: &[&str]) -> Option<String> {
+    let overrides = CLI_CONFIG_OVERRIDES.get()?;
+    keys.iter().find_map(|key| overrides.get(*key).cloned())
+}
+
+/// The effective, layered `wt.toml` configuration, plus a record of which
+/// layer each tracked field's value came from (for `wt config`'s benefit).
+#[derive(Debug)]
+pub struct LayeredConfig {
+    pub toml: WtToml,
+    pub base_branch_layer: ConfigLayer,
+    pub default_adapter_layer: ConfigLayer,
+    pub services_layer: ConfigLayer,
+    pub worktree_dir_layer: ConfigLayer,
+}
+
+impl LayeredConfig {
+    /// Resolve the effective config for `repo_root`, overlaying the global,
+    /// repo, and (if given) worktree layers in that order.
+    pub fn load(repo_root: &Path, worktree_path: Option<&Path>) -> Result<Self> {
+        let layers = [
+            (ConfigLayer::Global, WtToml::load_global()?),
+            (ConfigLayer::Repo, WtToml::load(repo_root)?),
+            (
+                ConfigLayer::Worktree,
+                match worktree_path {
+                    Some(p) => WtToml::load_worktree(p)?,
+                    None => None,
+                },
+            ),
+        ];
+
+        let mut toml = WtToml::default();
+        let mut base_branch_layer = ConfigLayer::Default;
+        let mut default_adapter_layer = ConfigLayer::Default;
+        let mut services_layer = ConfigLayer::Default;
+        let mut worktree_dir_layer = ConfigLayer::Default;
+
+        for (layer, layer_toml) in &layers {
+            let Some(layer_toml) = layer_toml else {
+                continue;
+            };
+            if layer_toml.base_branch.is_some() {
+                base_branch_layer = *layer;
+            }
+            if layer_toml.spawn.default_adapter.is_some() {
+                default_adapter_layer = *layer;
+            }
+            if !layer_toml.services.is_empty() {
+                services_layer = *layer;
+            }
+            if layer_toml.worktree_dir.is_some() {
+                worktree_dir_layer = *layer;
+            }
+            toml.overlay(layer_toml);
+        }
+
+        // WT_BASE_BRANCH overrides even a per-worktree .wt/config.toml — it's
+        // meant for one-off invocations (CI, a wrapper script) rather than a
+        // persisted setting. WT_DEFAULT_ADAPTER/WT_ON_CREATE_HOOK extend the
+        // same env-var layer to the other fields --config can target.
+        if let Ok(branch) = std::env::var("WT_BASE_BRANCH") {
+            toml.base_branch = Some(branch);
+            base_branch_layer = ConfigLayer::Env;
+        }
+        if let Ok(adapter) = std::env::var("WT_DEFAULT_ADAPTER") {
+            toml.spawn.default_adapter = Some(adapter);
+            default_adapter_layer = ConfigLayer::Env;
+        }
+        if let Ok(hook) = std::env::var("WT_ON_CREATE_HOOK") {
+            toml.hooks.on_create = Some(hook);
+        }
+        if let Ok(dir) = std::env::var("WT_WORKTREE_DIR") {
+            toml.worktree_dir = Some(dir);
+            worktree_dir_layer = ConfigLayer::Env;
+        }
+
+        // --config key=value, parsed once at startup into CLI_CONFIG_OVERRIDES,
+        // is the final and highest-precedence layer.
+        if let Some(branch) = cli_override(&["base_branch", "repo.base_branch"]) {
+            toml.base_branch = Some(branch);
+            base_branch_layer = ConfigLayer::Cli;
+        }
+        if let Some(adapter) = cli_override(&["agent.type", "spawn.default_adapter"]) {
+            toml.spawn.default_adapter = Some(adapter);
+            default_adapter_layer = ConfigLayer::Cli;
+        }
+        if let Some(hook) = cli_override(&["hooks.on_create"]) {
+            toml.hooks.on_create = Some(hook);
+        }
+        if let Some(dir) = cli_override(&["worktree_dir"]) {
+            toml.worktree_dir = Some(dir);
+            worktree_dir_layer = ConfigLayer::Cli;
+        }
+
+        Ok(Self {
+            toml,
+            base_branch_layer,
+            default_adapter_layer,
+            services_layer,
+            worktree_dir_layer,
+        })
+    }
+
+    /// Resolve the effective config for the current repository (and,
+    /// optionally, a specific worktree within it).
+    pub fn load_auto(worktree_path: Option<&Path>) -> Result<Self> {
+        let repo_root = crate::git::get_base_repo()?;
+        Self::load(&repo_root, worktree_path)
+    }
 }
 
-/// Get the base branch for the current repository (convenience function)
+/// Get the base branch for the current repository (convenience function).
+/// Prefers a `base_branch` set in the layered `wt.toml` stack (worktree >
+/// repo > global), falling back to the legacy flat `Config` store.
 pub fn get_base_branch() -> Result<String> {
     let repo_path = crate::git::get_base_repo()?;
+    let layered = LayeredConfig::load(&repo_path, None)?;
+    if let Some(branch) = layered.toml.base_branch {
+        return Ok(branch);
+    }
     let config = Config::load()?;
     Ok(config.get_base_branch(&repo_path))
 }
 
+/// Get the directory worktrees are created under, relative to the repo
+/// root (e.g. `".worktrees"`). Prefers the layered `wt.toml` stack's
+/// `worktree_dir`, falling back to the built-in default — there's no
+/// legacy flat-`Config` equivalent, since `worktree_dir` was always a
+/// `wt.toml`-only setting.
+pub fn get_worktree_dir_name() -> Result<String> {
+    let repo_path = crate::git::get_base_repo()?;
+    let layered = LayeredConfig::load(&repo_path, None)?;
+    Ok(layered
+        .toml
+        .worktree_dir
+        .unwrap_or_else(|| DEFAULT_WORKTREE_DIR.to_string()))
+}
+
 /// Read wt.toml from current repository (convenience function)
 pub fn read_wt_toml() -> Result<Option<WtToml>> {
     let repo_root = crate::git::get_base_repo()?;
     WtToml::load(&repo_root)
 }
 
+/// Read the effective, layered configuration (global → repo → worktree) for
+/// the current repository. Unlike [`read_wt_toml`], this always returns a
+/// value, since built-in defaults and the global layer may apply even when
+/// the repo has no `wt.toml` of its own.
+pub fn read_layered_wt_toml(worktree_path: Option<&Path>) -> Result<WtToml> {
+    let repo_root = crate::git::get_base_repo()?;
+    Ok(LayeredConfig::load(&repo_root, worktree_path)?.toml)
+}
+
 /// Run on-create hook if configured for current repo (convenience function)
 pub fn run_on_create_hook_for_repo(worktree_path: &Path) -> Result<()> {
     let repo_path = crate::git::get_base_repo()?;
@@ -300,19 +1096,75 @@ pub fn run_on_create_hook_for_repo(worktree_path: &Path) -> Result<()> {
 /// Configuration display for `wt config` command
 pub struct ConfigDisplay {
     pub effective_base: String,
+    /// Which layer `effective_base` was resolved from — `wt.toml`'s layered
+    /// stack (global/repo/worktree/env) if any of those set it, else the
+    /// legacy flat `Config` store, else the built-in default.
+    pub effective_base_layer: ConfigLayer,
     pub repo_base: Option<String>,
     pub global_base: Option<String>,
+    /// Effective worktree directory (e.g. `".worktrees"`) and the layer it
+    /// was resolved from.
+    pub worktree_dir: String,
+    pub worktree_dir_layer: ConfigLayer,
     pub on_create_hook: Option<String>,
+    pub on_remove_hook: Option<String>,
+    pub on_exit_hook: Option<String>,
+    pub pre_merge_hook: Option<String>,
+    pub post_merge_hook: Option<String>,
+    /// Adapter `wt spawn` uses when `--adapter` isn't passed, and the command
+    /// it runs — resolved the same way `resolve_adapter` does, so this is
+    /// what actually launches, not just what `wt.toml` says.
+    pub default_adapter: String,
+    pub default_adapter_command: String,
+    /// Every adapter name available from `[adapters.*]` in the layered
+    /// `wt.toml`, for visibility into what `--adapter` accepts.
+    pub available_adapters: Vec<String>,
 }
 
 impl ConfigDisplay {
     pub fn load(repo_path: &Path) -> Result<Self> {
         let config = Config::load()?;
+        let layered = LayeredConfig::load(repo_path, None)?;
+        let (default_adapter, adapter_config) = resolve_adapter(Some(&layered.toml), None);
+        let mut available_adapters: Vec<String> = layered.toml.adapters.keys().cloned().collect();
+        available_adapters.sort();
+
+        // Prefer wt.toml's layered base_branch (and its tracked layer) over
+        // the legacy flat Config store, matching get_base_branch()'s own
+        // precedence.
+        let (effective_base, effective_base_layer) = match &layered.toml.base_branch {
+            Some(branch) => (branch.clone(), layered.base_branch_layer),
+            None => (
+                config.get_base_branch(repo_path),
+                if config.get_repo_base_branch(repo_path).is_some() {
+                    ConfigLayer::Repo
+                } else if config.get_global_base_branch().is_some() {
+                    ConfigLayer::Global
+                } else {
+                    ConfigLayer::Default
+                },
+            ),
+        };
+
         Ok(Self {
-            effective_base: config.get_base_branch(repo_path),
+            effective_base,
+            effective_base_layer,
             repo_base: config.get_repo_base_branch(repo_path),
             global_base: config.get_global_base_branch(),
+            worktree_dir: layered
+                .toml
+                .worktree_dir
+                .clone()
+                .unwrap_or_else(|| DEFAULT_WORKTREE_DIR.to_string()),
+            worktree_dir_layer: layered.worktree_dir_layer,
             on_create_hook: config.get_on_create_hook(repo_path),
+            on_remove_hook: config.get_hook(repo_path, HookEvent::OnRemove),
+            on_exit_hook: config.get_hook(repo_path, HookEvent::OnExit),
+            pre_merge_hook: config.get_hook(repo_path, HookEvent::PreMerge),
+            post_merge_hook: config.get_hook(repo_path, HookEvent::PostMerge),
+            default_adapter,
+            default_adapter_command: adapter_config.command,
+            available_adapters,
         })
     }
 
@@ -324,6 +1176,17 @@ impl ConfigDisplay {
 }
 
 // Convenience functions for CLI that operate on current repo
+//
+// These go through the flat `Config` store (`~/.config/wt/config`), which is
+// a distinct layer from wt.toml: it holds a per-user, per-repo-path override
+// (and a global default) that isn't meant to be committed, whereas wt.toml's
+// `base_branch`/`hooks`/`adapters` fields are the project-wide default the
+// repo ships. An earlier `WtToml::edit`/`toml_edit`-backed mutator existed
+// here to rewrite wt.toml surgically, but nothing called it — routing these
+// functions through it would have conflated the two layers, not fixed a
+// format-loss bug, so it was removed rather than wired in. If a command is
+// added that edits wt.toml's own fields directly, reintroduce a
+// `toml_edit::DocumentMut`-based editor for that surface specifically.
 
 /// Get all config entries for --list
 pub fn list_all_config() -> Result<Vec<(String, String, String)>> {
@@ -397,8 +1260,76 @@ pub fn get_on_create_hook() -> Result<Option<String>> {
     Ok(config.get_on_create_hook(&repo_path))
 }
 
+/// Set the hook for `event` on the current repo
+pub fn set_repo_hook(event: HookEvent, command: &str) -> Result<()> {
+    let repo_path = crate::git::get_base_repo()?;
+    let mut config = Config::load()?;
+    config.set_hook(&repo_path, event, command);
+    config.save()
+}
+
+/// Unset the hook for `event` on the current repo
+pub fn unset_repo_hook(event: HookEvent) -> Result<()> {
+    let repo_path = crate::git::get_base_repo()?;
+    let mut config = Config::load()?;
+    config.unset_hook(&repo_path, event);
+    config.save()
+}
+
+/// Get the hook for `event` on the current repo
+pub fn get_repo_hook(event: HookEvent) -> Result<Option<String>> {
+    let repo_path = crate::git::get_base_repo()?;
+    let config = Config::load()?;
+    Ok(config.get_hook(&repo_path, event))
+}
+
 /// Check if wt.toml exists in current repo
 pub fn has_wt_toml() -> Result<bool> {
     let repo_root = crate::git::get_base_repo()?;
     Ok(WtToml::exists(&repo_root))
 }
+
+/// Resolve the adapter to use for spawning: an explicit `name`, falling back
+/// to `spawn.default_adapter` in wt.toml, falling back to the built-in
+/// `claude` adapter.
+pub fn resolve_adapter(toml: Option<&WtToml>, name: Option<&str>) -> (String, AdapterConfig) {
+    let toml_adapters = toml.map(|t| &t.adapters);
+
+    let resolved_name = name
+        .map(|n| n.to_string())
+        .or_else(|| toml.and_then(|t| t.spawn.default_adapter.clone()))
+        .unwrap_or_else(|| "claude".to_string());
+
+    let adapter = toml_adapters
+        .and_then(|adapters| adapters.get(&resolved_name).cloned())
+        .unwrap_or_else(AdapterConfig::claude_default);
+
+    (resolved_name, adapter)
+}
+
+impl WtToml {
+    /// Resolve the [`Adapter`](crate::adapter::Adapter) `wt` should drive:
+    /// an explicit `name`, falling back to `spawn.default_adapter`, falling
+    /// back to the built-in `claude` adapter — the same resolution order as
+    /// [`resolve_adapter`], wrapped as a trait object so callers (`wt
+    /// spawn`, `wt init`, `wt health`) can build commands, scaffold a
+    /// skills dir, or check `PATH` without matching on `AdapterConfig`
+    /// themselves.
+    pub fn adapter(&self, name: Option<&str>) -> Box<dyn crate::adapter::Adapter> {
+        crate::adapter::resolve(Some(self), name)
+    }
+}
+
+impl WtToml {
+    /// Whether this worktree's worker runs on another host over SSH, per a
+    /// `[remote]` block (see [`RemoteConfig`]).
+    pub fn is_remote(&self) -> bool {
+        self.remote.is_some()
+    }
+
+    /// The inverse of [`WtToml::is_remote`] — true when the worker runs on
+    /// this machine, the default.
+    pub fn is_local(&self) -> bool {
+        self.remote.is_none()
+    }
+}
@@ -3,67 +3,374 @@
 use std::path::PathBuf;
 use thiserror::Error;
 
+/// Every variant also carries a stable `#[diagnostic(code(...))]` and a
+/// `help(...)` remediation string, active only when built with the
+/// `diagnostics` feature — `wt`'s top-level error printer renders these as
+/// `miette::Report`s; without the feature they're plain `thiserror` errors
+/// with no extra dependency or binary size cost.
 #[derive(Error, Debug)]
+#[cfg_attr(feature = "diagnostics", derive(miette::Diagnostic))]
 pub enum Error {
     #[error("Not in a git repository")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::repo::not_found), help("run this from inside a git repository, or `git init` one first"))
+    )]
     NotInGitRepo,
 
     #[error("Not in a worktree")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::worktree::outside), help("run this from inside a worktree created by `wt new`"))
+    )]
     NotInWorktree,
 
     #[error("Worktree '{0}' already exists")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::worktree::exists), help("run `wt attach {0}` to jump to it, or pick a different name"))
+    )]
     WorktreeExists(String),
 
-    #[error("Worktree '{0}' does not exist")]
-    WorktreeNotFound(String),
+    #[error("Worktree '{name}' does not exist{}", crate::suggest::suffix(name, candidates))]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::worktree::not_found), help("run `wt list` to see available worktrees"))
+    )]
+    WorktreeNotFound { name: String, candidates: Vec<String> },
 
-    #[error("Worker '{0}' not found")]
-    WorkerNotFound(String),
+    #[error("Worker '{name}' not found{}", crate::suggest::suffix(name, candidates))]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::worker::not_found), help("run `wt list` to see running workers"))
+    )]
+    WorkerNotFound { name: String, candidates: Vec<String> },
 
-    #[error("Branch '{0}' does not exist")]
-    BranchNotFound(String),
+    #[error("Branch '{name}' does not exist{}", crate::suggest::suffix(name, candidates))]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::branch::not_found), help("run `git branch -a` to see available branches"))
+    )]
+    BranchNotFound { name: String, candidates: Vec<String> },
 
     #[error("Worktree has uncommitted changes. Use --force to override")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::worktree::dirty), help("run `wt rm --force` or commit your changes first"))
+    )]
     UncommittedChanges,
 
+    #[error("No stash found for '{0}'")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::stash::not_found), help("run `wt stash list` to see available stashes"))
+    )]
+    StashNotFound(String),
+
     #[error("No worktrees found")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::worktree::none), help("run `wt new <name>` to create one"))
+    )]
     NoWorktrees,
 
     #[error("Name is required")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::usage::name_required), help("pass a name, e.g. `wt new my-feature`"))
+    )]
     NameRequired,
 
     #[error("Config key '{0}' not found")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::config::not_found), help("run `wt config` with no arguments to see known keys"))
+    )]
     ConfigNotFound(String),
 
+    #[error("No registered project named '{0}'. Run 'wt projects' to list known projects")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::project::not_found), help("run `wt projects` to list known projects"))
+    )]
+    ProjectNotFound(String),
+
     #[error("Already initialized. Use --force to reinitialize")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::init::already_done), help("run `wt init --force` to reinitialize"))
+    )]
     AlreadyInitialized,
 
     #[error("Missing dependency: {0}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::deps::missing), help("install it and make sure it's on PATH, then run `wt health`"))
+    )]
     MissingDependency(String),
 
+    /// Rolls up every dependency problem [`crate::preflight::check`] finds
+    /// (absent or too old) in one error, instead of a fix-one-rerun loop
+    /// through [`Error::MissingDependency`].
+    #[error("{}", format_dependency_issues(.0))]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::deps::missing_many), help("install or upgrade the listed tools, then run `wt health`"))
+    )]
+    MissingDependencies(Vec<crate::preflight::DependencyIssue>),
+
     #[error("Tmux session not found: {0}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::tmux::session_not_found), help("run `wt spawn` to start a session, or check `tmux ls`"))
+    )]
     TmuxSessionNotFound(String),
 
+    #[error("A task named '{0}' already exists. Use a different name or `wt kill {0}` first")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::spawn::duplicate), help("run `wt kill {0}` first, or pick a different name"))
+    )]
+    DuplicateSpawn(String),
+
+    #[error("A tmux window named '{0}' already exists in this session")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::tmux::duplicate_window), help("run `wt kill {0}` first, or pick a different name"))
+    )]
+    DuplicateWindow(String),
+
     #[error("Git error: {0}")]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(wt::git::failed)))]
     Git(String),
 
+    /// A shelled-out command exited non-zero. Keeps the exact argv, exit
+    /// code, and captured stderr around instead of collapsing them into a
+    /// [`Error::Git`] string, so the cause survives all the way to the
+    /// top-level error printer.
+    #[error("{program} {} failed (exit {}): {stderr}", args.join(" "), status.map(|c| c.to_string()).unwrap_or_else(|| "unknown".to_string()))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(wt::process::failed)))]
+    Process {
+        program: String,
+        args: Vec<String>,
+        status: Option<i32>,
+        stderr: String,
+    },
+
+    #[error("Tmux error: {0}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::tmux::failed), help("check `tmux -V` and that a tmux server is reachable"))
+    )]
+    Tmux(String),
+
     #[error("IO error: {0}")]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(wt::io::failed)))]
     Io(#[from] std::io::Error),
 
     #[error("JSON error: {0}")]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(wt::json::invalid)))]
     Json(#[from] serde_json::Error),
 
-    #[error("TOML parse error: {0}")]
-    TomlParse(#[from] toml::de::Error),
+    #[error("TOML parse error: {source}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::config::toml_invalid), help("fix the highlighted region of wt.toml"))
+    )]
+    TomlParse {
+        #[source]
+        source: toml::de::Error,
+        /// Raw file text, rendered as the diagnostic's source snippet under
+        /// the `diagnostics` feature.
+        #[cfg_attr(feature = "diagnostics", source_code)]
+        src: String,
+        /// Byte span of the offending region within `src`, from
+        /// [`toml::de::Error::span`].
+        #[cfg_attr(feature = "diagnostics", label("{source}"))]
+        span: std::ops::Range<usize>,
+    },
 
     #[error("Invalid path: {0}")]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(wt::path::invalid)))]
     InvalidPath(PathBuf),
 
     #[error("State error: {0}")]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(wt::state::failed)))]
     State(String),
 
+    #[error(
+        "State file was written by a newer wt (version {found}, this binary understands up to {understood}). Upgrade wt before touching this repo's worktrees"
+    )]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(wt::state::too_new), help("upgrade wt to a version that understands state version {found}"))
+    )]
+    StateTooNew { found: u32, understood: u32 },
+
     #[error("{0}")]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(wt::custom)))]
     Custom(String),
 }
 
+/// Bulleted `Display` body for [`Error::MissingDependencies`].
+fn format_dependency_issues(issues: &[crate::preflight::DependencyIssue]) -> String {
+    let mut out = String::from("Missing or outdated dependencies:");
+    for issue in issues {
+        out.push_str("\n  - ");
+        out.push_str(&issue.to_string());
+    }
+    out
+}
+
+impl Error {
+    /// Build a [`Error::TomlParse`] from a failed [`toml::from_str`], keeping
+    /// the file's raw text and the parser's byte span around so the
+    /// `diagnostics` feature can underline the offending region instead of
+    /// just naming it in prose.
+    pub fn toml_parse(source: toml::de::Error, src: String) -> Self {
+        let span = source.span().unwrap_or(0..0);
+        Error::TomlParse { source, src, span }
+    }
+}
+
+/// Process exit codes `wt` surfaces for scripting, so a shell wrapper or CI
+/// job can branch on failure class instead of pattern-matching stderr. `0`
+/// (success) and `1` (generic/unclassified error) keep their usual meaning
+/// for backward compatibility; everything above is specific to `wt`.
+pub mod exit_code {
+    /// Command completed successfully.
+    pub const SUCCESS: i32 = 0;
+    /// Unclassified failure — kept for backward compatibility with scripts
+    /// that only check for a non-zero exit.
+    pub const GENERIC: i32 = 1;
+    /// ENOENT-like: not in a git repo/worktree, or the named worktree,
+    /// branch, worker, config key, or tmux session doesn't exist.
+    pub const NOT_FOUND: i32 = 2;
+    /// Refused because the worktree has uncommitted changes or unmerged
+    /// commits that `--force` would discard.
+    pub const DIRTY: i32 = 3;
+    /// EACCES-like: a permission error, or the operation is blocked by
+    /// something already held (a lock, a duplicate name).
+    pub const UNAVAILABLE: i32 = 4;
+    /// EINVAL-like: bad usage — a required argument was missing or invalid.
+    pub const USAGE: i32 = 5;
+}
+
+impl Error {
+    /// The exit code this error should surface as, per [`exit_code`].
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::NotInGitRepo
+            | Error::NotInWorktree
+            | Error::WorktreeNotFound { .. }
+            | Error::WorkerNotFound { .. }
+            | Error::BranchNotFound { .. }
+            | Error::StashNotFound(_)
+            | Error::NoWorktrees
+            | Error::ConfigNotFound(_)
+            | Error::ProjectNotFound(_)
+            | Error::TmuxSessionNotFound(_) => exit_code::NOT_FOUND,
+
+            Error::UncommittedChanges => exit_code::DIRTY,
+
+            Error::WorktreeExists(_)
+            | Error::AlreadyInitialized
+            | Error::DuplicateSpawn(_)
+            | Error::DuplicateWindow(_) => exit_code::UNAVAILABLE,
+            Error::MissingDependency(_) => exit_code::UNAVAILABLE,
+            Error::MissingDependencies(_) => exit_code::UNAVAILABLE,
+            Error::StateTooNew { .. } => exit_code::UNAVAILABLE,
+            Error::Io(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+                exit_code::UNAVAILABLE
+            }
+
+            Error::NameRequired => exit_code::USAGE,
+
+            Error::Git(_)
+            | Error::Process { .. }
+            | Error::Tmux(_)
+            | Error::Io(_)
+            | Error::Json(_)
+            | Error::TomlParse { .. }
+            | Error::InvalidPath(_)
+            | Error::State(_)
+            | Error::Custom(_) => exit_code::GENERIC,
+        }
+    }
+
+    /// Stable, snake_case identifier for this variant, for [`Error::report`]'s
+    /// `code` field. Unlike the `Display` message, this never changes across
+    /// releases, so a `--json`-consuming script can match on it instead of
+    /// grepping human-readable text.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Error::NotInGitRepo => "not_in_git_repo",
+            Error::NotInWorktree => "not_in_worktree",
+            Error::WorktreeExists(_) => "worktree_exists",
+            Error::WorktreeNotFound { .. } => "worktree_not_found",
+            Error::WorkerNotFound { .. } => "worker_not_found",
+            Error::BranchNotFound { .. } => "branch_not_found",
+            Error::UncommittedChanges => "uncommitted_changes",
+            Error::StashNotFound(_) => "stash_not_found",
+            Error::NoWorktrees => "no_worktrees",
+            Error::NameRequired => "name_required",
+            Error::ConfigNotFound(_) => "config_not_found",
+            Error::ProjectNotFound(_) => "project_not_found",
+            Error::AlreadyInitialized => "already_initialized",
+            Error::MissingDependency(_) => "missing_dependency",
+            Error::MissingDependencies(_) => "missing_dependencies",
+            Error::TmuxSessionNotFound(_) => "tmux_session_not_found",
+            Error::DuplicateSpawn(_) => "duplicate_spawn",
+            Error::DuplicateWindow(_) => "duplicate_window",
+            Error::Git(_) => "git",
+            Error::Process { .. } => "process",
+            Error::Tmux(_) => "tmux",
+            Error::Io(_) => "io",
+            Error::Json(_) => "json",
+            Error::TomlParse { .. } => "toml_parse",
+            Error::InvalidPath(_) => "invalid_path",
+            Error::State(_) => "state",
+            Error::StateTooNew { .. } => "state_too_new",
+            Error::Custom(_) => "custom",
+        }
+    }
+
+    /// Render this error as [`ErrorReport`] for `--json` scripting: the
+    /// stable `code`, the same text `Display` prints, and whatever
+    /// structured `details` the variant carries (`null` if none).
+    pub fn report(&self) -> ErrorReport {
+        let details = match self {
+            Error::WorktreeNotFound { name, candidates }
+            | Error::WorkerNotFound { name, candidates }
+            | Error::BranchNotFound { name, candidates } => {
+                serde_json::json!({ "name": name, "candidates": candidates })
+            }
+            Error::Process { program, args, status, stderr } => {
+                serde_json::json!({ "program": program, "args": args, "status": status, "stderr": stderr })
+            }
+            Error::StateTooNew { found, understood } => {
+                serde_json::json!({ "found": found, "understood": understood })
+            }
+            Error::MissingDependencies(issues) => serde_json::json!({ "issues": issues }),
+            _ => serde_json::Value::Null,
+        };
+
+        ErrorReport {
+            code: self.code().to_string(),
+            message: self.to_string(),
+            details,
+        }
+    }
+}
+
+/// Machine-readable rendering of an [`Error`], for the `--json` flag: a
+/// stable `code` a script can match on, the human `message`, and whatever
+/// structured `details` the variant carries.
+#[derive(Debug, serde::Serialize)]
+pub struct ErrorReport {
+    pub code: String,
+    pub message: String,
+    pub details: serde_json::Value,
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
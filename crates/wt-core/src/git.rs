@@ -9,6 +9,18 @@ use std::process::Command;
 use crate::error::{Error, Result};
 use crate::worker::DiffStats;
 
+/// Build an [`Error::Process`] from a `git` invocation that exited non-zero,
+/// keeping the exact argv, exit code, and stderr instead of collapsing them
+/// into a bare [`Error::Git`] string.
+fn process_error(cmd: &Command, output: &std::process::Output) -> Error {
+    Error::Process {
+        program: cmd.get_program().to_string_lossy().to_string(),
+        args: cmd.get_args().map(|a| a.to_string_lossy().to_string()).collect(),
+        status: output.status.code(),
+        stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    }
+}
+
 /// Get the root directory of the git repository (alias for get_repo_root)
 pub fn repo_root() -> Result<PathBuf> {
     get_repo_root()
@@ -56,10 +68,13 @@ pub fn get_base_repo() -> Result<PathBuf> {
     Ok(git_common.parent().unwrap_or(&git_common).to_path_buf())
 }
 
-/// Get the worktrees directory (.worktrees in the base repo)
+/// Get the worktrees directory in the base repo. Honors a layered `wt.toml`
+/// `worktree_dir` override (see [`crate::config::get_worktree_dir_name`]),
+/// falling back to `.worktrees`.
 pub fn get_worktrees_dir() -> Result<PathBuf> {
     let base = get_base_repo()?;
-    Ok(base.join(".worktrees"))
+    let dir_name = crate::config::get_worktree_dir_name().unwrap_or_else(|_| ".worktrees".to_string());
+    Ok(base.join(dir_name))
 }
 
 /// Ensure worktrees are excluded from git (convenience wrapper)
@@ -165,14 +180,12 @@ fn find_worktrees_recursive(
 
 /// List all git worktrees (including base repo)
 pub fn list_all_worktrees() -> Result<Vec<(PathBuf, String)>> {
-    let output = Command::new("git")
-        .args(["worktree", "list", "--porcelain"])
-        .output()?;
+    let mut cmd = Command::new("git");
+    cmd.args(["worktree", "list", "--porcelain"]);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Err(Error::Git(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+        return Err(process_error(&cmd, &output));
     }
 
     let text = String::from_utf8_lossy(&output.stdout);
@@ -200,6 +213,230 @@ pub fn list_all_worktrees() -> Result<Vec<(PathBuf, String)>> {
     Ok(worktrees)
 }
 
+/// Structured information about a single git worktree, as reported by
+/// `git worktree list --porcelain`.
+#[derive(Debug, Clone)]
+pub struct WorktreeInfo {
+    pub path: PathBuf,
+    pub branch: String,
+    /// Reason the worktree is locked, if it is (from the porcelain `locked`
+    /// line). `Some("")` means it's locked with no reason given.
+    pub locked: Option<String>,
+}
+
+/// List all git worktrees (including the base repo), with full porcelain
+/// detail such as lock state.
+pub fn list_all_worktrees_info() -> Result<Vec<WorktreeInfo>> {
+    let mut cmd = Command::new("git");
+    cmd.args(["worktree", "list", "--porcelain"]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut worktrees = Vec::new();
+    let mut path: Option<PathBuf> = None;
+    let mut branch = String::new();
+    let mut locked: Option<String> = None;
+
+    let mut flush = |path: &mut Option<PathBuf>, branch: &mut String, locked: &mut Option<String>| {
+        if let Some(path) = path.take() {
+            worktrees.push(WorktreeInfo {
+                path,
+                branch: std::mem::take(branch),
+                locked: locked.take(),
+            });
+        }
+    };
+
+    for line in text.lines() {
+        if let Some(p) = line.strip_prefix("worktree ") {
+            path = Some(PathBuf::from(p));
+        } else if let Some(b) = line.strip_prefix("branch refs/heads/") {
+            branch = b.to_string();
+        } else if line == "locked" {
+            locked = Some(String::new());
+        } else if let Some(reason) = line.strip_prefix("locked ") {
+            locked = Some(reason.to_string());
+        } else if line.is_empty() {
+            flush(&mut path, &mut branch, &mut locked);
+        }
+    }
+    flush(&mut path, &mut branch, &mut locked);
+
+    Ok(worktrees)
+}
+
+/// Diff/ahead summary for one worktree, as computed by
+/// [`list_worktrees_with_stats`]. `error` is set (leaving `stats` and
+/// `commits_ahead` at their defaults) when the git invocations for that
+/// single worktree failed, so one bad worktree can't abort the rest of the
+/// listing.
+#[derive(Debug, Clone, Default)]
+pub struct DiffSummary {
+    pub stats: DiffStats,
+    pub commits_ahead: usize,
+    pub error: Option<String>,
+}
+
+fn compute_diff_summary(path: &Path, base_branch: &str) -> DiffSummary {
+    match (
+        get_diff_stats(path, base_branch),
+        get_commits_ahead(path, base_branch),
+    ) {
+        (Ok(stats), Ok(ahead)) => DiffSummary {
+            stats,
+            commits_ahead: ahead.len(),
+            error: None,
+        },
+        (stats, ahead) => DiffSummary {
+            stats: stats.unwrap_or_default(),
+            commits_ahead: ahead.map(|a| a.len()).unwrap_or(0),
+            error: Some(
+                stats
+                    .err()
+                    .or(ahead.err())
+                    .map(|e| e.to_string())
+                    .unwrap_or_default(),
+            ),
+        },
+    }
+}
+
+/// List all worktrees along with their diff stats and commits-ahead count
+/// against `base_branch`, computed in fixed-size batches of up to
+/// `batch_size` worktrees running concurrently on worker threads.
+///
+/// Results are streamed back through a channel as each worktree in a batch
+/// finishes, so a caller (table/TUI renderer) can paint rows as they arrive
+/// instead of waiting for the whole set — while `batch_size` still bounds
+/// how many `git` invocations run at once so the machine stays responsive
+/// on repos with many worktrees. A failure computing one worktree's stats
+/// is attached to that entry's `error` rather than aborting the others.
+pub fn list_worktrees_with_stats(
+    base_branch: &str,
+    batch_size: usize,
+) -> Result<impl Iterator<Item = (WorktreeInfo, DiffSummary)>> {
+    let worktrees = list_all_worktrees_info()?;
+    let base_branch = base_branch.to_string();
+    let batch_size = batch_size.max(1);
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        for batch in worktrees.chunks(batch_size) {
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = batch
+                    .iter()
+                    .map(|info| {
+                        let info = info.clone();
+                        let base_branch = &base_branch;
+                        scope.spawn(move || {
+                            let summary = compute_diff_summary(&info.path, base_branch);
+                            (info, summary)
+                        })
+                    })
+                    .collect();
+
+                for handle in handles {
+                    if let Ok(result) = handle.join() {
+                        let _ = tx.send(result);
+                    }
+                }
+            });
+        }
+    });
+
+    Ok(rx.into_iter())
+}
+
+/// Lock a worktree so `git worktree prune` and `--force` removal refuse to
+/// touch it, e.g. because it lives on removable media.
+pub fn lock_worktree(path: &Path, reason: Option<&str>) -> Result<()> {
+    let path_str = path.to_string_lossy();
+    let mut args = vec!["worktree", "lock"];
+    if let Some(reason) = reason {
+        args.push("--reason");
+        args.push(reason);
+    }
+    args.push(&path_str);
+
+    let mut cmd = Command::new("git");
+    cmd.args(&args);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
+/// Unlock a previously locked worktree.
+pub fn unlock_worktree(path: &Path) -> Result<()> {
+    let mut cmd = Command::new("git");
+    cmd.args(["worktree", "unlock", &path.to_string_lossy()]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
+/// Relocate a worktree directory, fixing up the admin files that link it
+/// back to the main repo.
+pub fn move_worktree(from: &Path, to: &Path) -> Result<()> {
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "worktree",
+        "move",
+        &from.to_string_lossy(),
+        &to.to_string_lossy(),
+    ]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
+/// Garbage-collect administrative entries for worktrees whose directories
+/// were deleted manually (e.g. with `rm -rf`) instead of `wt remove`.
+pub fn prune_worktrees() -> Result<()> {
+    let mut cmd = Command::new("git");
+    cmd.args(["worktree", "prune"]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
+/// Repair the bidirectional links between the main repo and the given
+/// worktree paths, e.g. after the repo or a worktree was moved.
+pub fn repair_worktrees(paths: &[PathBuf]) -> Result<()> {
+    let mut args = vec!["worktree", "repair"];
+    let path_strs: Vec<String> = paths.iter().map(|p| p.to_string_lossy().to_string()).collect();
+    args.extend(path_strs.iter().map(|s| s.as_str()));
+
+    let mut cmd = Command::new("git");
+    cmd.args(&args);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
 /// Check if a branch exists
 pub fn branch_exists(branch: &str) -> Result<bool> {
     // Handle remote branches
@@ -227,46 +464,116 @@ pub fn branch_exists(branch: &str) -> Result<bool> {
 
 /// Get the current branch name
 pub fn get_current_branch() -> Result<String> {
-    let output = Command::new("git")
-        .args(["rev-parse", "--abbrev-ref", "HEAD"])
-        .output()?;
+    let mut cmd = Command::new("git");
+    cmd.args(["rev-parse", "--abbrev-ref", "HEAD"]);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Err(Error::Git("Failed to get current branch".to_string()));
+        return Err(process_error(&cmd, &output));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
+/// A branch candidate for `wt create`'s picker, as reported by
+/// [`list_branches`].
+#[derive(Debug, Clone)]
+pub struct BranchInfo {
+    /// Short branch name (no `refs/heads/`/`refs/remotes/origin/` prefix).
+    pub name: String,
+    /// Whether a worktree already checks this branch out.
+    pub has_worktree: bool,
+    /// Unix timestamp of the branch tip's committer time, for sorting
+    /// candidates by recency the way a branch-picker UI would.
+    pub unix_timestamp: i64,
+}
+
+/// Enumerate local and `origin/`-remote branches with their tip's commit
+/// timestamp, local branches taking precedence over a same-named remote
+/// one. One `git for-each-ref` covers both ref namespaces so this stays a
+/// single subprocess regardless of branch count.
+pub fn list_branches() -> Result<Vec<BranchInfo>> {
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "for-each-ref",
+        "--format=%(refname)\t%(committerdate:unix)",
+        "refs/heads/",
+        "refs/remotes/origin/",
+    ]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    let worktree_branches: std::collections::HashSet<String> = list_all_worktrees()?
+        .into_iter()
+        .map(|(_, branch)| branch)
+        .collect();
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut locals: Vec<(String, i64)> = Vec::new();
+    let mut remotes: Vec<(String, i64)> = Vec::new();
+
+    for line in text.lines() {
+        let Some((refname, timestamp)) = line.split_once('\t') else {
+            continue;
+        };
+        let Ok(unix_timestamp) = timestamp.trim().parse::<i64>() else {
+            continue;
+        };
+
+        if let Some(name) = refname.strip_prefix("refs/heads/") {
+            locals.push((name.to_string(), unix_timestamp));
+        } else if let Some(name) = refname.strip_prefix("refs/remotes/origin/") {
+            remotes.push((name.to_string(), unix_timestamp));
+        }
+    }
+
+    // A local branch always wins over a same-named remote one, since it's
+    // the one `wt` would actually check a worktree out onto — collect
+    // locals first so the later remote pass can skip duplicates.
+    let mut by_name: std::collections::HashMap<String, BranchInfo> = std::collections::HashMap::new();
+    for (name, unix_timestamp) in locals {
+        let has_worktree = worktree_branches.contains(&name);
+        by_name.insert(name.clone(), BranchInfo { name, has_worktree, unix_timestamp });
+    }
+    for (name, unix_timestamp) in remotes {
+        by_name.entry(name.clone()).or_insert_with(|| {
+            let has_worktree = worktree_branches.contains(&name);
+            BranchInfo { name, has_worktree, unix_timestamp }
+        });
+    }
+
+    let mut branches: Vec<BranchInfo> = by_name.into_values().collect();
+    branches.sort_by(|a, b| b.unix_timestamp.cmp(&a.unix_timestamp));
+    Ok(branches)
+}
+
 /// Create a git worktree
 pub fn create_worktree(path: &Path, branch: &str, base_branch: &str) -> Result<()> {
     // Check if branch exists
     let branch_exists_already = branch_exists(branch)?;
 
-    let output = if branch_exists_already {
-        Command::new("git")
-            .args(["worktree", "add", &path.to_string_lossy(), branch])
-            .output()?
+    let mut cmd = Command::new("git");
+    if branch_exists_already {
+        cmd.args(["worktree", "add", &path.to_string_lossy(), branch]);
     } else {
         // Create new branch from base
         let start_point = find_valid_start_point(base_branch)?;
-
-        Command::new("git")
-            .args([
-                "worktree",
-                "add",
-                "-b",
-                branch,
-                &path.to_string_lossy(),
-                &start_point,
-            ])
-            .output()?
-    };
+        cmd.args([
+            "worktree",
+            "add",
+            "-b",
+            branch,
+            &path.to_string_lossy(),
+            &start_point,
+        ]);
+    }
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Err(Error::Git(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+        return Err(process_error(&cmd, &output));
     }
 
     // Set up push tracking for new branches
@@ -285,6 +592,99 @@ pub fn create_worktree(path: &Path, branch: &str, base_branch: &str) -> Result<(
     Ok(())
 }
 
+/// Clone `url` into `dest` for a `[projects.<name>]` repo on first use,
+/// honoring its configured `branch` if any. No-op if `dest` already exists.
+pub fn clone_repo(url: &str, dest: &Path, branch: Option<&str>) -> Result<()> {
+    if dest.exists() {
+        return Ok(());
+    }
+
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut args = vec!["clone"];
+    if let Some(branch) = branch {
+        args.push("-b");
+        args.push(branch);
+    }
+    let dest_str = dest.to_string_lossy();
+    args.push(url);
+    args.push(&dest_str);
+
+    let mut cmd = Command::new("git");
+    cmd.args(&args);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
+/// Set up upstream tracking for a newly created branch in `path`, per a
+/// remote and optional prefix (the prefix is prepended to `branch` to form
+/// the remote-side branch name, e.g. prefix `"wip/"` + branch `"feature"` =>
+/// `<remote>/wip/feature`). Tries `branch --set-upstream-to` first (the
+/// remote branch already exists), falling back to `push -u` to create it.
+pub fn set_upstream_tracking(path: &Path, branch: &str, remote: &str, prefix: Option<&str>) -> Result<()> {
+    let remote_branch = format!("{}{}", prefix.unwrap_or(""), branch);
+
+    let output = Command::new("git")
+        .args([
+            "-C",
+            &path.to_string_lossy(),
+            "branch",
+            &format!("--set-upstream-to={}/{}", remote, remote_branch),
+        ])
+        .output()?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+
+    // Remote branch doesn't exist yet; create it via push -u.
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "-C",
+        &path.to_string_lossy(),
+        "push",
+        "-u",
+        remote,
+        &format!("{}:{}", branch, remote_branch),
+    ]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
+/// Delete a local branch. Refuses to touch any branch in `protected`
+/// (e.g. `persistent_branches` from config), even with `force`.
+pub fn delete_branch(branch: &str, force: bool, protected: &[String]) -> Result<()> {
+    if protected.iter().any(|p| p == branch) {
+        return Err(Error::Custom(format!(
+            "Branch '{}' is protected and cannot be deleted",
+            branch
+        )));
+    }
+
+    let flag = if force { "-D" } else { "-d" };
+    let mut cmd = Command::new("git");
+    cmd.args(["branch", flag, branch]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
 /// Find a valid start point for creating a new branch
 fn find_valid_start_point(base_branch: &str) -> Result<String> {
     // Try the base branch as-is first
@@ -342,41 +742,529 @@ fn find_valid_start_point(base_branch: &str) -> Result<String> {
         return Ok(sha);
     }
 
-    Err(Error::BranchNotFound(base_branch.to_string()))
+    Err(Error::BranchNotFound {
+        name: base_branch.to_string(),
+        candidates: list_branches()
+            .map(|branches| branches.into_iter().map(|b| b.name).collect())
+            .unwrap_or_default(),
+    })
 }
 
-/// Remove a git worktree
+/// Remove a git worktree. Refuses to `--force` through a locked worktree
+/// (one protected with [`lock_worktree`]) unless `force_locked` is set, so a
+/// worktree deliberately locked against accidental removal isn't destroyed
+/// by a careless `--force`.
 pub fn remove_worktree(path: &Path, force: bool) -> Result<()> {
+    remove_worktree_ex(path, force, false)
+}
+
+/// Like [`remove_worktree`], but `force_locked` allows forcing through a
+/// locked worktree too (git's own `worktree remove --force --force` double).
+pub fn remove_worktree_ex(path: &Path, force: bool, force_locked: bool) -> Result<()> {
+    if force && !force_locked {
+        if let Some(info) = list_all_worktrees_info()?
+            .into_iter()
+            .find(|wt| wt.path == path)
+        {
+            if info.locked.is_some() {
+                return Err(Error::Custom(format!(
+                    "Worktree '{}' is locked; unlock it first or pass --force twice to override",
+                    path.display()
+                )));
+            }
+        }
+    }
+
     let path_str = path.to_string_lossy();
     let mut args = vec!["worktree", "remove"];
     if force {
         args.push("--force");
     }
+    if force_locked {
+        args.push("--force");
+    }
     args.push(&path_str);
 
-    let output = Command::new("git").args(&args).output()?;
+    let mut cmd = Command::new("git");
+    cmd.args(&args);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr);
         if stderr.contains("contains modified or untracked files") {
             return Err(Error::UncommittedChanges);
         }
-        return Err(Error::Git(stderr));
+        return Err(process_error(&cmd, &output));
     }
 
     Ok(())
 }
 
-/// Check if worktree has uncommitted changes
-pub fn has_uncommitted_changes(path: &Path) -> Result<bool> {
+/// The state of a single side (staged or unstaged) of a file's status, per
+/// the `X`/`Y` characters of `git status --porcelain=v2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileStatus {
+    Unmodified,
+    Modified,
+    Added,
+    Deleted,
+    Renamed,
+    Copied,
+    Untracked,
+    Ignored,
+    Conflicted,
+}
+
+impl FileStatus {
+    fn from_char(c: char) -> Self {
+        match c {
+            'M' => FileStatus::Modified,
+            'A' => FileStatus::Added,
+            'D' => FileStatus::Deleted,
+            'R' => FileStatus::Renamed,
+            'C' => FileStatus::Copied,
+            '?' => FileStatus::Untracked,
+            '!' => FileStatus::Ignored,
+            'U' => FileStatus::Conflicted,
+            _ => FileStatus::Unmodified,
+        }
+    }
+}
+
+/// A single file's status, as reported by `git status --porcelain=v2`.
+#[derive(Debug, Clone)]
+pub struct StatusEntry {
+    /// Repo-relative path.
+    pub path: String,
+    /// Path this file was renamed/copied from, if any.
+    pub rename_from: Option<String>,
+    /// Index (staged) state; `X` in the porcelain `XY` field.
+    pub staged: FileStatus,
+    /// Worktree (unstaged) state; `Y` in the porcelain `XY` field.
+    pub unstaged: FileStatus,
+}
+
+impl StatusEntry {
+    pub fn is_conflicted(&self) -> bool {
+        matches!(self.staged, FileStatus::Conflicted) || matches!(self.unstaged, FileStatus::Conflicted)
+    }
+}
+
+/// Get structured, per-file status for a worktree by parsing
+/// `git status --porcelain=v2`.
+///
+/// Porcelain v2 lines come in four kinds: ordinary changed entries (`1 XY
+/// ... path`), renames/copies (`2 XY ... path\tfrom-path`), unmerged
+/// conflicts (`u XY ... path`), and untracked/ignored files (`? path` /
+/// `! path`).
+pub fn get_status(path: &Path) -> Result<Vec<StatusEntry>> {
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "-C",
+        &path.to_string_lossy(),
+        "status",
+        "--porcelain=v2",
+    ]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut entries = Vec::new();
+
+    for line in text.lines() {
+        let mut fields = line.split(' ');
+        let kind = match fields.next() {
+            Some(k) => k,
+            None => continue,
+        };
+
+        match kind {
+            "?" | "!" => {
+                let status = FileStatus::from_char(kind.chars().next().unwrap());
+                if let Some(file_path) = line.splitn(2, ' ').nth(1) {
+                    entries.push(StatusEntry {
+                        path: file_path.to_string(),
+                        rename_from: None,
+                        staged: status,
+                        unstaged: status,
+                    });
+                }
+            }
+            "1" | "2" | "u" => {
+                // Fields: <kind> <XY> <sub> <mH> <mI> <mW> <hH> <hI> <path> [<origPath>]
+                let parts: Vec<&str> = line.splitn(9, ' ').collect();
+                if parts.len() < 9 {
+                    continue;
+                }
+                let xy = parts[1];
+                let mut xy_chars = xy.chars();
+                let x = xy_chars.next().unwrap_or('.');
+                let y = xy_chars.next().unwrap_or('.');
+                let rest = parts[8];
+
+                let (file_path, rename_from) = if kind == "2" {
+                    match rest.split_once('\t') {
+                        Some((p, from)) => (p.to_string(), Some(from.to_string())),
+                        None => (rest.to_string(), None),
+                    }
+                } else {
+                    (rest.to_string(), None)
+                };
+
+                entries.push(StatusEntry {
+                    path: file_path,
+                    rename_from,
+                    staged: FileStatus::from_char(x),
+                    unstaged: FileStatus::from_char(y),
+                });
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Render a compact human-readable summary of structured status entries,
+/// e.g. "3 staged, 1 modified, 2 untracked".
+pub fn summarize_status(entries: &[StatusEntry]) -> String {
+    let mut staged = 0;
+    let mut modified = 0;
+    let mut untracked = 0;
+    let mut conflicted = 0;
+
+    for entry in entries {
+        if entry.is_conflicted() {
+            conflicted += 1;
+            continue;
+        }
+        if entry.unstaged == FileStatus::Untracked {
+            untracked += 1;
+            continue;
+        }
+        if entry.staged != FileStatus::Unmodified {
+            staged += 1;
+        }
+        if entry.unstaged != FileStatus::Unmodified {
+            modified += 1;
+        }
+    }
+
+    let mut parts = Vec::new();
+    if conflicted > 0 {
+        parts.push(format!("{} conflicted", conflicted));
+    }
+    if staged > 0 {
+        parts.push(format!("{} staged", staged));
+    }
+    if modified > 0 {
+        parts.push(format!("{} modified", modified));
+    }
+    if untracked > 0 {
+        parts.push(format!("{} untracked", untracked));
+    }
+
+    if parts.is_empty() {
+        "clean".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// Ahead/behind commit counts plus per-category file counts, modeled after a
+/// shell prompt's git status segment (e.g. starship's `git_status` module).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RichStatus {
+    pub ahead: usize,
+    pub behind: usize,
+    /// True when both `ahead` and `behind` are non-zero, i.e. the branch has
+    /// been rebased/force-pushed past, or local history forked, from base.
+    pub diverged: bool,
+    pub staged: usize,
+    pub modified: usize,
+    pub untracked: usize,
+    pub renamed: usize,
+    pub deleted: usize,
+    pub conflicted: usize,
+    /// Whether `git stash list` has any entries for this worktree.
+    pub stashed: bool,
+}
+
+impl RichStatus {
+    /// No ahead/behind divergence, no pending file changes, no stash.
+    pub fn is_clean(&self) -> bool {
+        self.ahead == 0
+            && self.behind == 0
+            && self.staged == 0
+            && self.modified == 0
+            && self.untracked == 0
+            && self.renamed == 0
+            && self.deleted == 0
+            && self.conflicted == 0
+            && !self.stashed
+    }
+
+    /// Compact, prompt-style rendering, e.g. `⇡3 ⇣1 +2 !1 ?4 =1 $`. Categories
+    /// at zero are omitted; a fully clean worktree renders as an empty string.
+    pub fn render_compact(&self) -> String {
+        let mut parts = Vec::new();
+        if self.ahead > 0 {
+            parts.push(format!("⇡{}", self.ahead));
+        }
+        if self.behind > 0 {
+            parts.push(format!("⇣{}", self.behind));
+        }
+        if self.staged > 0 {
+            parts.push(format!("+{}", self.staged));
+        }
+        if self.modified > 0 {
+            parts.push(format!("!{}", self.modified));
+        }
+        if self.untracked > 0 {
+            parts.push(format!("?{}", self.untracked));
+        }
+        if self.renamed > 0 {
+            parts.push(format!("»{}", self.renamed));
+        }
+        if self.deleted > 0 {
+            parts.push(format!("✘{}", self.deleted));
+        }
+        if self.conflicted > 0 {
+            parts.push(format!("={}", self.conflicted));
+        }
+        if self.stashed {
+            parts.push("$".to_string());
+        }
+        parts.join(" ")
+    }
+}
+
+/// Get ahead/behind commit counts relative to `base_branch`, via
+/// `git rev-list --left-right --count base...HEAD` (`behind` first, `ahead`
+/// second, per `--left-right`'s left-is-base/right-is-HEAD ordering).
+fn get_ahead_behind(path: &Path, base_branch: &str) -> Result<(usize, usize)> {
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "-C",
+        &path.to_string_lossy(),
+        "rev-list",
+        "--left-right",
+        "--count",
+        &format!("{}...HEAD", base_branch),
+    ]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut counts = text.split_whitespace();
+    let behind: usize = counts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    let ahead: usize = counts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    Ok((ahead, behind))
+}
+
+/// Whether `path` has any stashed changes.
+fn has_stash(path: &Path) -> bool {
+    Command::new("git")
+        .args(["-C", &path.to_string_lossy(), "stash", "list"])
+        .output()
+        .map(|output| output.status.success() && !output.stdout.is_empty())
+        .unwrap_or(false)
+}
+
+/// Compute a full, prompt-style status summary for a worktree: ahead/behind
+/// counts relative to `base_branch`, plus per-category file counts parsed
+/// from `git status --porcelain=v2` and whether a stash is present. Used by
+/// `wt ps` to show more than a bare dirty/clean flag.
+pub fn get_rich_status(path: &Path, base_branch: &str) -> Result<RichStatus> {
+    let (ahead, behind) = get_ahead_behind(path, base_branch).unwrap_or((0, 0));
+    let entries = get_status(path)?;
+
+    let mut status = RichStatus {
+        ahead,
+        behind,
+        diverged: ahead > 0 && behind > 0,
+        stashed: has_stash(path),
+        ..Default::default()
+    };
+
+    for entry in &entries {
+        if entry.is_conflicted() {
+            status.conflicted += 1;
+            continue;
+        }
+        if entry.unstaged == FileStatus::Untracked {
+            status.untracked += 1;
+            continue;
+        }
+        if entry.staged == FileStatus::Renamed || entry.unstaged == FileStatus::Renamed {
+            status.renamed += 1;
+            continue;
+        }
+        if entry.staged == FileStatus::Deleted || entry.unstaged == FileStatus::Deleted {
+            status.deleted += 1;
+            continue;
+        }
+        if entry.staged != FileStatus::Unmodified {
+            status.staged += 1;
+        }
+        if entry.unstaged != FileStatus::Unmodified {
+            status.modified += 1;
+        }
+    }
+
+    Ok(status)
+}
+
+/// A worktree's dirty-state summary plus full rich status, as computed by
+/// [`refresh_all_statuses`].
+#[derive(Debug, Clone, Default)]
+pub struct StatusSummary {
+    pub summary: String,
+    pub rich: RichStatus,
+}
+
+/// Default batch size for [`refresh_all_statuses`]/[`list_worktrees_with_stats`]:
+/// the CPU count, so the worker pool stays bounded to what the machine can
+/// actually run concurrently instead of an arbitrary fixed constant.
+pub fn default_batch_size() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(4)
+}
+
+fn compute_status_summary(path: &Path, base_branch: &str) -> StatusSummary {
+    let summary = get_status(path)
+        .map(|entries| summarize_status(&entries))
+        .unwrap_or_else(|_| "unknown".to_string());
+    let rich = get_rich_status(path, base_branch).unwrap_or_default();
+    StatusSummary { summary, rich }
+}
+
+/// Batched, non-blocking status refresh across many worktrees at once.
+///
+/// `wt ps`/`wt status` computing `get_status`/`get_rich_status` serially,
+/// one worktree at a time, stalls the whole command on a repo with many
+/// workers. This runs them in fixed-size batches of up to `batch_size`
+/// worktrees on worker threads, same as [`list_worktrees_with_stats`], and
+/// streams results back through a channel as each worktree finishes so a
+/// caller can render incrementally instead of waiting for the slowest one.
+pub fn refresh_all_statuses(
+    worktrees: &[PathBuf],
+    base_branch: &str,
+    batch_size: usize,
+) -> impl Iterator<Item = (PathBuf, StatusSummary)> {
+    let worktrees = worktrees.to_vec();
+    let base_branch = base_branch.to_string();
+    let batch_size = batch_size.max(1);
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        for batch in worktrees.chunks(batch_size) {
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = batch
+                    .iter()
+                    .map(|path| {
+                        let path = path.clone();
+                        let base_branch = &base_branch;
+                        scope.spawn(move || {
+                            let summary = compute_status_summary(&path, base_branch);
+                            (path, summary)
+                        })
+                    })
+                    .collect();
+
+                for handle in handles {
+                    if let Ok(result) = handle.join() {
+                        let _ = tx.send(result);
+                    }
+                }
+            });
+        }
+    });
+
+    rx.into_iter()
+}
+
+/// Ahead/behind commit counts of a worktree's checked-out branch relative to
+/// its configured remote-tracking upstream (`@{u}`) — distinct from
+/// [`RichStatus::ahead`]/`behind`, which are relative to the base branch.
+/// Returns `Ok(None)` rather than an error when no upstream is configured,
+/// since that's an ordinary state (a worker branch that hasn't been pushed
+/// yet), not a failure.
+pub fn get_upstream_ahead_behind(path: &Path) -> Result<Option<(usize, usize)>> {
     let output = Command::new("git")
-        .args(["-C", &path.to_string_lossy(), "status", "--porcelain"])
+        .args([
+            "-C",
+            &path.to_string_lossy(),
+            "rev-list",
+            "--left-right",
+            "--count",
+            "@{u}...HEAD",
+        ])
         .output()?;
 
     if !output.status.success() {
-        return Err(Error::Git(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+        return Ok(None);
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut counts = text.split_whitespace();
+    let behind: usize = counts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    let ahead: usize = counts.next().and_then(|s| s.parse().ok()).unwrap_or(0);
+    Ok(Some((ahead, behind)))
+}
+
+/// A worktree's HEAD commit, for display in `wt ps`/`wt status`.
+#[derive(Debug, Clone, Default)]
+pub struct CommitInfo {
+    pub short_sha: String,
+    pub summary: String,
+    pub author: String,
+    /// Human relative time, e.g. `"2 hours ago"` (git's `%ar`).
+    pub relative_time: String,
+}
+
+/// Get a worktree's HEAD commit: short sha, summary, author, and relative
+/// time, via a single `git log -1`.
+pub fn get_last_commit(path: &Path) -> Result<CommitInfo> {
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "-C",
+        &path.to_string_lossy(),
+        "log",
+        "-1",
+        "--format=%h\x1f%s\x1f%an\x1f%ar",
+    ]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut fields = text.trim().split('\x1f');
+    Ok(CommitInfo {
+        short_sha: fields.next().unwrap_or_default().to_string(),
+        summary: fields.next().unwrap_or_default().to_string(),
+        author: fields.next().unwrap_or_default().to_string(),
+        relative_time: fields.next().unwrap_or_default().to_string(),
+    })
+}
+
+/// Check if worktree has uncommitted changes
+pub fn has_uncommitted_changes(path: &Path) -> Result<bool> {
+    let mut cmd = Command::new("git");
+    cmd.args(["-C", &path.to_string_lossy(), "status", "--porcelain"]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
     }
 
     Ok(!output.stdout.is_empty())
@@ -404,20 +1292,18 @@ pub fn get_commits_ahead(path: &Path, base_branch: &str) -> Result<Vec<String>>
 
 /// Get diff stat for a worktree
 pub fn get_diff_stat(path: &Path, base_branch: &str) -> Result<String> {
-    let output = Command::new("git")
-        .args([
-            "-C",
-            &path.to_string_lossy(),
-            "diff",
-            "--stat",
-            base_branch,
-        ])
-        .output()?;
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "-C",
+        &path.to_string_lossy(),
+        "diff",
+        "--stat",
+        base_branch,
+    ]);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Err(Error::Git(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+        return Err(process_error(&cmd, &output));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
@@ -425,37 +1311,78 @@ pub fn get_diff_stat(path: &Path, base_branch: &str) -> Result<String> {
 
 /// Get diff stats as structured data
 pub fn get_diff_stats(path: &Path, base_branch: &str) -> Result<DiffStats> {
-    let output = Command::new("git")
-        .args([
-            "-C",
-            &path.to_string_lossy(),
-            "diff",
-            "--numstat",
-            base_branch,
-        ])
-        .output()?;
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "-C",
+        &path.to_string_lossy(),
+        "diff",
+        "--numstat",
+        base_branch,
+    ]);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Ok(DiffStats::default());
+        // Don't collapse a bad base branch or an unreadable worktree into a
+        // fake "nothing changed" — that misleads a reviewer into thinking a
+        // worker did nothing.
+        return Err(process_error(&cmd, &output));
     }
 
     let text = String::from_utf8_lossy(&output.stdout);
     let mut stats = DiffStats::default();
 
+    // Unmerged conflicts don't report a meaningful line count either; cross
+    // reference status so a conflicted file gets its own reason instead of
+    // being lumped in with plain binary files.
+    let conflicted: std::collections::HashSet<String> = get_status(path)
+        .map(|entries| {
+            entries
+                .into_iter()
+                .filter(|e| e.is_conflicted())
+                .map(|e| e.path)
+                .collect()
+        })
+        .unwrap_or_default();
+
     for line in text.lines() {
         let parts: Vec<&str> = line.split('\t').collect();
         if parts.len() >= 3 {
+            let path = parts[2].to_string();
+            stats.files_changed += 1;
+
+            if conflicted.contains(&path) {
+                stats.files.push(crate::worker::FileDiff {
+                    path,
+                    insertions: 0,
+                    deletions: 0,
+                    error: Some(crate::worker::FileDiffError::Conflict),
+                });
+                continue;
+            }
+
+            // `git diff --numstat` reports binary files as "-\t-\tpath"
+            // instead of a line count; treat that as an explicit error
+            // rather than silently parsing it as "0 insertions, 0 deletions".
+            if parts[0] == "-" || parts[1] == "-" {
+                stats.files.push(crate::worker::FileDiff {
+                    path,
+                    insertions: 0,
+                    deletions: 0,
+                    error: Some(crate::worker::FileDiffError::Binary),
+                });
+                continue;
+            }
+
             let insertions: usize = parts[0].parse().unwrap_or(0);
             let deletions: usize = parts[1].parse().unwrap_or(0);
-            let path = parts[2].to_string();
 
-            stats.files_changed += 1;
             stats.insertions += insertions;
             stats.deletions += deletions;
             stats.files.push(crate::worker::FileDiff {
                 path,
                 insertions,
                 deletions,
+                error: None,
             });
         }
     }
@@ -465,14 +1392,12 @@ pub fn get_diff_stats(path: &Path, base_branch: &str) -> Result<DiffStats> {
 
 /// Get full diff for a worktree
 pub fn get_diff(path: &Path, base_branch: &str) -> Result<String> {
-    let output = Command::new("git")
-        .args(["-C", &path.to_string_lossy(), "diff", base_branch])
-        .output()?;
+    let mut cmd = Command::new("git");
+    cmd.args(["-C", &path.to_string_lossy(), "diff", base_branch]);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Err(Error::Git(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+        return Err(process_error(&cmd, &output));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).to_string())
@@ -480,14 +1405,12 @@ pub fn get_diff(path: &Path, base_branch: &str) -> Result<String> {
 
 /// Merge a branch into the current branch
 pub fn merge_branch(branch: &str) -> Result<()> {
-    let output = Command::new("git")
-        .args(["merge", branch, "--no-edit"])
-        .output()?;
+    let mut cmd = Command::new("git");
+    cmd.args(["merge", branch, "--no-edit"]);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Err(Error::Git(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+        return Err(process_error(&cmd, &output));
     }
 
     Ok(())
@@ -495,20 +1418,18 @@ pub fn merge_branch(branch: &str) -> Result<()> {
 
 /// Get worktree branch
 pub fn get_worktree_branch(path: &Path) -> Result<String> {
-    let output = Command::new("git")
-        .args([
-            "-C",
-            &path.to_string_lossy(),
-            "rev-parse",
-            "--abbrev-ref",
-            "HEAD",
-        ])
-        .output()?;
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "-C",
+        &path.to_string_lossy(),
+        "rev-parse",
+        "--abbrev-ref",
+        "HEAD",
+    ]);
+    let output = cmd.output()?;
 
     if !output.status.success() {
-        return Err(Error::Git(
-            String::from_utf8_lossy(&output.stderr).to_string(),
-        ));
+        return Err(process_error(&cmd, &output));
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
@@ -534,3 +1455,69 @@ pub fn ensure_worktrees_excluded(git_common_dir: &Path) -> Result<()> {
 
     Ok(())
 }
+
+/// Fetch `remote`'s refs into `path`'s repo, so a followed ref like
+/// `origin/main` reflects what's actually on the remote before rebasing
+/// onto it.
+pub fn fetch_remote(path: &Path, remote: &str) -> Result<()> {
+    let mut cmd = Command::new("git");
+    cmd.args(["-C", &path.to_string_lossy(), "fetch", remote]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(())
+}
+
+/// Outcome of [`rebase_onto`] when it completes without conflicting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebaseOutcome {
+    /// `onto` hadn't moved since the branch last rebased onto it.
+    UpToDate,
+    /// The branch's own commits were replayed on top of `onto`.
+    Rebased(usize),
+}
+
+/// A rebase stopped partway through with conflicts. Left in place (not
+/// aborted) so the caller can report it and the user can resolve it with an
+/// ordinary `git rebase --continue`/`--abort` in the worktree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RebaseConflict;
+
+fn count_commits(path: &Path, range: &str) -> Result<usize> {
+    let mut cmd = Command::new("git");
+    cmd.args(["-C", &path.to_string_lossy(), "rev-list", "--count", range]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        return Err(process_error(&cmd, &output));
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .map_err(|_| Error::Git(format!("could not parse `git rev-list --count {}`", range)))
+}
+
+/// Rebase `path`'s checked-out branch onto `onto` (e.g. `"origin/main"`),
+/// replaying only the worktree's own commits. Returns `Ok(UpToDate)` without
+/// touching the worktree if `onto` hasn't advanced past it.
+pub fn rebase_onto(path: &Path, onto: &str) -> Result<std::result::Result<RebaseOutcome, RebaseConflict>> {
+    let behind = count_commits(path, &format!("HEAD..{}", onto))?;
+    if behind == 0 {
+        return Ok(Ok(RebaseOutcome::UpToDate));
+    }
+    let replayed = count_commits(path, &format!("{}..HEAD", onto))?;
+
+    let output = Command::new("git")
+        .args(["-C", &path.to_string_lossy(), "rebase", onto])
+        .output()?;
+
+    if output.status.success() {
+        Ok(Ok(RebaseOutcome::Rebased(replayed)))
+    } else {
+        Ok(Err(RebaseConflict))
+    }
+}
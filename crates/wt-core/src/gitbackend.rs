@@ -0,0 +1,719 @@
+//! Pluggable git read/diff backend
+//!
+//! Every operation in [`crate::git`] shells out to a `git` subprocess. That's
+//! fine for one-off commands, but listing many worktrees multiplies into
+//! dozens of forks (list + diff stats + commits-ahead, per worktree). This
+//! trait lets performance-sensitive call sites (`wt ps`, `wt status`) route
+//! the read-heavy operations through `libgit2` in-process instead, while
+//! worktree admin operations `git2` doesn't support (add/move/repair) keep
+//! shelling out via [`CliGitReadBackend`].
+
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::git::{self, StatusEntry, WorktreeInfo};
+use crate::worker::DiffStats;
+
+/// Read-heavy git operations, implementable either by shelling out to the
+/// `git` CLI or by talking to `libgit2` in-process.
+pub trait GitReadBackend {
+    fn current_branch(&self, path: &Path) -> Result<String>;
+    fn branch_exists(&self, branch: &str) -> Result<bool>;
+    fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>>;
+    fn diff_stats(&self, path: &Path, base_branch: &str) -> Result<DiffStats>;
+    fn commits_ahead(&self, path: &Path, base_branch: &str) -> Result<usize>;
+    fn status(&self, path: &Path) -> Result<Vec<StatusEntry>>;
+    /// Whether `path` has any pending changes (staged, unstaged, or
+    /// untracked). Cheaper than `status` for callers that only need a
+    /// boolean, since a backend can short-circuit on the first entry.
+    fn has_changes(&self, path: &Path) -> Result<bool> {
+        Ok(!self.status(path)?.is_empty())
+    }
+    fn merge(&self, branch: &str) -> Result<()>;
+}
+
+/// Shells out to the `git` CLI for every operation. Always available, and
+/// the only backend that supports worktree add/move/repair.
+pub struct CliGitReadBackend;
+
+impl GitReadBackend for CliGitReadBackend {
+    fn current_branch(&self, path: &Path) -> Result<String> {
+        git::get_worktree_branch(path)
+    }
+
+    fn branch_exists(&self, branch: &str) -> Result<bool> {
+        git::branch_exists(branch)
+    }
+
+    fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
+        git::list_all_worktrees_info()
+    }
+
+    fn diff_stats(&self, path: &Path, base_branch: &str) -> Result<DiffStats> {
+        git::get_diff_stats(path, base_branch)
+    }
+
+    fn commits_ahead(&self, path: &Path, base_branch: &str) -> Result<usize> {
+        Ok(git::get_commits_ahead(path, base_branch)?.len())
+    }
+
+    fn status(&self, path: &Path) -> Result<Vec<StatusEntry>> {
+        git::get_status(path)
+    }
+
+    fn has_changes(&self, path: &Path) -> Result<bool> {
+        git::has_uncommitted_changes(path)
+    }
+
+    fn merge(&self, branch: &str) -> Result<()> {
+        git::merge_branch(branch)
+    }
+}
+
+/// In-process `libgit2` implementation. Computes diff stats via the diff
+/// API, ahead/behind via `graph_ahead_behind`, and status via the status
+/// API — all without forking a `git` subprocess. Worktree listing still
+/// defers to [`CliGitReadBackend`] for the porcelain lock-reason detail `git2`
+/// doesn't expose, and merges requiring a real working-tree merge fall back
+/// to the CLI too.
+#[cfg(feature = "libgit2")]
+pub struct Git2Backend {
+    repo: git2::Repository,
+}
+
+#[cfg(feature = "libgit2")]
+impl Git2Backend {
+    pub fn open(repo_root: &Path) -> Result<Self> {
+        let repo = git2::Repository::open(repo_root).map_err(|e| Error::Git(e.to_string()))?;
+        Ok(Self { repo })
+    }
+
+    /// Add a worktree for `branch` at `path`, in-process via `git2`'s
+    /// worktree API instead of shelling out to `git worktree add`. `branch`
+    /// is created from `base_branch` first if it doesn't already exist.
+    pub fn add_worktree(&self, path: &Path, branch: &str, base_branch: &str) -> Result<()> {
+        let reference = match self.repo.find_branch(branch, git2::BranchType::Local) {
+            Ok(b) => b.into_reference(),
+            Err(_) => {
+                let commit = self.find_valid_start_point(base_branch)?;
+                self.repo
+                    .branch(branch, &commit, false)
+                    .map_err(|e| Error::Git(e.to_string()))?
+                    .into_reference()
+            }
+        };
+
+        let mut opts = git2::WorktreeAddOptions::new();
+        opts.reference(Some(&reference));
+        self.repo
+            .worktree(branch, path, Some(&opts))
+            .map_err(|e| Error::Git(e.to_string()))?;
+        Ok(())
+    }
+
+    /// `git2` equivalent of [`crate::git`]'s private `find_valid_start_point`:
+    /// tries `base_branch` as-is, then `origin/<base_branch>`, then
+    /// `refs/heads/<base_branch>`, then `refs/remotes/origin/<base_branch>`
+    /// (or `refs/remotes/<base_branch>` if it's already `origin/`-prefixed),
+    /// so a worktree created from a bare branch name resolves the same way
+    /// whether the configured backend shells out or stays in-process.
+    fn find_valid_start_point(&self, base_branch: &str) -> Result<git2::Commit<'_>> {
+        let candidates = [
+            base_branch.to_string(),
+            if !base_branch.starts_with("origin/") {
+                format!("origin/{}", base_branch)
+            } else {
+                base_branch.to_string()
+            },
+            format!("refs/heads/{}", base_branch),
+            if base_branch.starts_with("origin/") {
+                format!("refs/remotes/{}", base_branch)
+            } else {
+                format!("refs/remotes/origin/{}", base_branch)
+            },
+        ];
+
+        for candidate in &candidates {
+            if let Ok(obj) = self.repo.revparse_single(candidate) {
+                return obj.peel_to_commit().map_err(|e| Error::Git(e.to_string()));
+            }
+        }
+
+        Err(Error::Git(format!(
+            "no valid start point found for '{}'",
+            base_branch
+        )))
+    }
+
+    /// Remove a worktree in-process: prunes its admin entry via `git2` and
+    /// deletes its working directory. Unlike `git worktree remove`, `git2`
+    /// has no "refuse if dirty" guard built in, so callers must check
+    /// [`GitReadBackend::status`] first if that matters.
+    pub fn remove_worktree(&self, name: &str, path: &Path) -> Result<()> {
+        if let Ok(worktree) = self.repo.find_worktree(name) {
+            let mut opts = git2::WorktreePruneOptions::new();
+            opts.valid(true).working_tree(true);
+            worktree
+                .prune(Some(&mut opts))
+                .map_err(|e| Error::Git(e.to_string()))?;
+        }
+        if path.exists() {
+            std::fs::remove_dir_all(path)?;
+        }
+        Ok(())
+    }
+}
+
+/// The libgit2 version `wt` was linked against, for `wt health` to report.
+/// `None` when built without the `libgit2` feature.
+#[cfg(feature = "libgit2")]
+pub fn libgit2_version() -> Option<String> {
+    let (major, minor, patch) = git2::Version::get().libgit2_version();
+    Some(format!("{}.{}.{}", major, minor, patch))
+}
+
+#[cfg(not(feature = "libgit2"))]
+pub fn libgit2_version() -> Option<String> {
+    None
+}
+
+/// Tag a stash message with the worktree it came from, so [`find_stash`] can
+/// recognize it again after the worktree (and its stash-less `git` CLI) is
+/// long gone.
+fn stash_message(name: &str, branch: &str) -> String {
+    format!("wt: {} ({})", name, branch)
+}
+
+/// Save `worktree_path`'s dirty state (tracked and untracked) to the stash,
+/// tagged with `name`/`branch` via [`stash_message`]. `git worktree`s can't
+/// normally be stashed from — the `git` CLI refuses with "loose object file"
+/// errors or an outright "cannot stash in a linked worktree" — so this goes
+/// through `libgit2`'s stash API directly instead of shelling out. Returns
+/// `Ok(false)` if the worktree was already clean and there was nothing to do.
+#[cfg(feature = "libgit2")]
+pub fn stash_save(worktree_path: &Path, name: &str, branch: &str) -> Result<bool> {
+    let mut repo = git2::Repository::open(worktree_path).map_err(|e| Error::Git(e.to_string()))?;
+    let signature = repo
+        .signature()
+        .or_else(|_| git2::Signature::now("wt", "wt@localhost"))
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+    match repo.stash_save2(
+        &signature,
+        Some(&stash_message(name, branch)),
+        Some(git2::StashFlags::INCLUDE_UNTRACKED),
+    ) {
+        Ok(_) => Ok(true),
+        Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(false),
+        Err(e) => Err(Error::Git(e.to_string())),
+    }
+}
+
+#[cfg(not(feature = "libgit2"))]
+pub fn stash_save(_worktree_path: &Path, _name: &str, _branch: &str) -> Result<bool> {
+    Err(Error::MissingDependency(
+        "libgit2 (rebuild wt with the `libgit2` feature to use --stash)".to_string(),
+    ))
+}
+
+/// Find the most recent stash tagged for `name` by [`stash_save`], searching
+/// `repo_root`'s stash list. Returns its stash index (for [`stash_pop`]) and
+/// the branch recorded in its message.
+#[cfg(feature = "libgit2")]
+pub fn find_stash(repo_root: &Path, name: &str) -> Result<Option<(usize, String)>> {
+    let mut repo = git2::Repository::open(repo_root).map_err(|e| Error::Git(e.to_string()))?;
+    let prefix = format!("wt: {} (", name);
+
+    let mut found = None;
+    repo.stash_foreach(|index, message, _oid| {
+        if message.starts_with(&prefix) {
+            let branch = message[prefix.len()..].trim_end_matches(')').to_string();
+            found = Some((index, branch));
+            false
+        } else {
+            true
+        }
+    })
+    .map_err(|e| Error::Git(e.to_string()))?;
+
+    Ok(found)
+}
+
+#[cfg(not(feature = "libgit2"))]
+pub fn find_stash(_repo_root: &Path, _name: &str) -> Result<Option<(usize, String)>> {
+    Err(Error::MissingDependency(
+        "libgit2 (rebuild wt with the `libgit2` feature to use --stash)".to_string(),
+    ))
+}
+
+/// Apply and drop the stash at `index` onto `worktree_path`. The stash is
+/// left in place if the apply reports conflicts, so nothing is lost.
+#[cfg(feature = "libgit2")]
+pub fn stash_pop(worktree_path: &Path, index: usize) -> Result<()> {
+    let mut repo = git2::Repository::open(worktree_path).map_err(|e| Error::Git(e.to_string()))?;
+    repo.stash_apply(index, None)
+        .map_err(|e| Error::Git(e.to_string()))?;
+    repo.stash_drop(index).map_err(|e| Error::Git(e.to_string()))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "libgit2"))]
+pub fn stash_pop(_worktree_path: &Path, _index: usize) -> Result<()> {
+    Err(Error::MissingDependency(
+        "libgit2 (rebuild wt with the `libgit2` feature to use --stash)".to_string(),
+    ))
+}
+
+/// How [`merge_branch_analyzed`] integrated a branch into the base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeOutcome {
+    /// The base already contained everything in the branch; nothing to do.
+    UpToDate,
+    /// The base ref was moved forward without creating a merge commit.
+    FastForwarded,
+    /// A three-way merge was performed and committed.
+    Merged,
+}
+
+/// Why [`merge_branch_analyzed`] didn't complete the merge.
+#[derive(Debug, Clone)]
+pub enum MergeFailure {
+    /// `ff_only` was set but the merge analysis reported it can't be
+    /// fast-forwarded.
+    NotFastForward,
+    /// The three-way merge left these paths conflicted. The base worktree's
+    /// index and working tree are left as `git merge` would leave them, for
+    /// the caller to resolve by hand; nothing is committed.
+    Conflicts(Vec<String>),
+}
+
+/// Classify and perform the merge of `branch` into `base_repo`'s current
+/// branch via `libgit2`'s merge-analysis step, rather than always shelling
+/// out to `git merge` and hoping for the best.
+///
+/// - Up to date: reports nothing to do.
+/// - Fast-forwardable (and `no_ff` isn't set): moves the ref forward, no
+///   merge commit.
+/// - Otherwise: performs a real three-way merge from the merge-base. On
+///   conflict, the conflicted paths are returned and nothing is committed.
+///
+/// `ff_only` turns a would-be three-way merge into an error instead.
+#[cfg(feature = "libgit2")]
+pub fn merge_branch_analyzed(
+    base_repo: &Path,
+    branch: &str,
+    ff_only: bool,
+    no_ff: bool,
+) -> Result<std::result::Result<MergeOutcome, MergeFailure>> {
+    let repo = git2::Repository::open(base_repo).map_err(|e| Error::Git(e.to_string()))?;
+    let branch_ref = repo
+        .find_branch(branch, git2::BranchType::Local)
+        .map_err(|e| Error::Git(e.to_string()))?;
+    let annotated = repo
+        .reference_to_annotated_commit(branch_ref.get())
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+    let (analysis, _preference) = repo
+        .merge_analysis(&[&annotated])
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+    if analysis.is_up_to_date() {
+        return Ok(Ok(MergeOutcome::UpToDate));
+    }
+
+    if ff_only && !analysis.is_fast_forward() {
+        return Ok(Err(MergeFailure::NotFastForward));
+    }
+
+    if analysis.is_fast_forward() && !no_ff {
+        let mut head_ref = repo.head().map_err(|e| Error::Git(e.to_string()))?;
+        let ref_name = head_ref
+            .name()
+            .ok_or_else(|| Error::Git("HEAD is not a branch".to_string()))?
+            .to_string();
+        head_ref
+            .set_target(annotated.id(), &format!("wt merge: fast-forward to {}", branch))
+            .map_err(|e| Error::Git(e.to_string()))?;
+        repo.set_head(&ref_name).map_err(|e| Error::Git(e.to_string()))?;
+        repo.checkout_head(Some(git2::build::CheckoutBuilder::default().force()))
+            .map_err(|e| Error::Git(e.to_string()))?;
+        return Ok(Ok(MergeOutcome::FastForwarded));
+    }
+
+    repo.merge(&[&annotated], None, None)
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+    let mut index = repo.index().map_err(|e| Error::Git(e.to_string()))?;
+    if index.has_conflicts() {
+        let mut conflicted = Vec::new();
+        for conflict in index.conflicts().map_err(|e| Error::Git(e.to_string()))? {
+            let conflict = conflict.map_err(|e| Error::Git(e.to_string()))?;
+            let path = conflict
+                .our
+                .or(conflict.their)
+                .or(conflict.ancestor)
+                .map(|entry| String::from_utf8_lossy(&entry.path).to_string());
+            if let Some(path) = path {
+                conflicted.push(path);
+            }
+        }
+        conflicted.sort();
+        conflicted.dedup();
+        return Ok(Err(MergeFailure::Conflicts(conflicted)));
+    }
+
+    let tree_oid = index
+        .write_tree_to(&repo)
+        .map_err(|e| Error::Git(e.to_string()))?;
+    let tree = repo.find_tree(tree_oid).map_err(|e| Error::Git(e.to_string()))?;
+    let head_commit = repo
+        .head()
+        .and_then(|r| r.peel_to_commit())
+        .map_err(|e| Error::Git(e.to_string()))?;
+    let branch_commit = repo
+        .find_commit(annotated.id())
+        .map_err(|e| Error::Git(e.to_string()))?;
+    let signature = repo
+        .signature()
+        .or_else(|_| git2::Signature::now("wt", "wt@localhost"))
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+    repo.commit(
+        Some("HEAD"),
+        &signature,
+        &signature,
+        &format!("Merge branch '{}'", branch),
+        &tree,
+        &[&head_commit, &branch_commit],
+    )
+    .map_err(|e| Error::Git(e.to_string()))?;
+    repo.cleanup_state().map_err(|e| Error::Git(e.to_string()))?;
+
+    Ok(Ok(MergeOutcome::Merged))
+}
+
+/// Build an [`Error::Process`] from a `git` invocation that exited
+/// non-zero, keeping the argv/exit code/stderr intact instead of
+/// collapsing them into a bare [`Error::Git`] string — mirrors `git.rs`'s
+/// and `vcs.rs`'s own copies of this helper.
+#[cfg(not(feature = "libgit2"))]
+fn process_error(cmd: &std::process::Command, output: &std::process::Output) -> Error {
+    Error::Process {
+        program: cmd.get_program().to_string_lossy().to_string(),
+        args: cmd.get_args().map(|a| a.to_string_lossy().to_string()).collect(),
+        status: output.status.code(),
+        stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    }
+}
+
+/// `git merge-base --is-ancestor ancestor descendant`: `Ok(true)` if
+/// `ancestor` is reachable from `descendant` (exit 0), `Ok(false)` if it
+/// isn't (exit 1, the documented "no" result), and `Err` for anything else
+/// (e.g. an unknown ref).
+#[cfg(not(feature = "libgit2"))]
+fn is_ancestor(base_repo: &Path, ancestor: &str, descendant: &str) -> Result<bool> {
+    let mut cmd = std::process::Command::new("git");
+    cmd.args(["-C", &base_repo.to_string_lossy(), "merge-base", "--is-ancestor", ancestor, descendant]);
+    let output = cmd.output()?;
+    match output.status.code() {
+        Some(0) => Ok(true),
+        Some(1) => Ok(false),
+        _ => Err(process_error(&cmd, &output)),
+    }
+}
+
+#[cfg(not(feature = "libgit2"))]
+pub fn merge_branch_analyzed(
+    base_repo: &Path,
+    branch: &str,
+    ff_only: bool,
+    no_ff: bool,
+) -> Result<std::result::Result<MergeOutcome, MergeFailure>> {
+    if is_ancestor(base_repo, branch, "HEAD")? {
+        return Ok(Ok(MergeOutcome::UpToDate));
+    }
+
+    let can_fast_forward = is_ancestor(base_repo, "HEAD", branch)?;
+
+    if ff_only && !can_fast_forward {
+        return Ok(Err(MergeFailure::NotFastForward));
+    }
+
+    if can_fast_forward && !no_ff {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(["-C", &base_repo.to_string_lossy(), "merge", "--ff-only", branch]);
+        let output = cmd.output()?;
+        if !output.status.success() {
+            return Err(process_error(&cmd, &output));
+        }
+        return Ok(Ok(MergeOutcome::FastForwarded));
+    }
+
+    let mut cmd = std::process::Command::new("git");
+    cmd.args(["-C", &base_repo.to_string_lossy(), "merge", "--no-edit", "--no-ff", branch]);
+    let output = cmd.output()?;
+
+    if !output.status.success() {
+        let conflicted: Vec<String> = git::get_status(base_repo)?
+            .into_iter()
+            .filter(|e| e.is_conflicted())
+            .map(|e| e.path)
+            .collect();
+        if !conflicted.is_empty() {
+            return Ok(Err(MergeFailure::Conflicts(conflicted)));
+        }
+        return Err(process_error(&cmd, &output));
+    }
+
+    Ok(Ok(MergeOutcome::Merged))
+}
+
+/// How [`integrate_worktree`] should land a worker branch on top of base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// [`merge_branch_analyzed`]'s up-to-date/fast-forward/three-way merge.
+    Merge,
+    /// Rebase the worker branch onto `base_branch` first (via
+    /// [`git::rebase_onto`]), then fast-forward base onto it, for teams that
+    /// want a linear history instead of a merge commit.
+    Rebase,
+}
+
+/// Integrate `branch` (checked out at `worktree_path`) into `base_repo`'s
+/// current branch per `strategy`.
+///
+/// `Strategy::Rebase` replays the worktree's own commits onto `base_branch`
+/// in place; a conflict there is left unresolved in the worktree (same as
+/// [`git::rebase_onto`] leaves it) for the user to finish with
+/// `git rebase --continue`/`--abort`, and reported as [`MergeFailure::Conflicts`]
+/// with the paths `git status` shows conflicted. Once the rebase lands
+/// cleanly, base is always fast-forwardable onto the rebased branch, so the
+/// second step never produces a three-way merge commit.
+pub fn integrate_worktree(
+    worktree_path: &Path,
+    base_repo: &Path,
+    branch: &str,
+    base_branch: &str,
+    strategy: Strategy,
+) -> Result<std::result::Result<MergeOutcome, MergeFailure>> {
+    match strategy {
+        Strategy::Merge => merge_branch_analyzed(base_repo, branch, false, false),
+        Strategy::Rebase => match git::rebase_onto(worktree_path, base_branch)? {
+            Ok(git::RebaseOutcome::UpToDate) => Ok(Ok(MergeOutcome::UpToDate)),
+            Ok(git::RebaseOutcome::Rebased(_)) => merge_branch_analyzed(base_repo, branch, true, false),
+            Err(git::RebaseConflict) => {
+                let conflicted = git::get_status(worktree_path)
+                    .map(|entries| {
+                        entries
+                            .into_iter()
+                            .filter(|e| e.is_conflicted())
+                            .map(|e| e.path)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                Ok(Err(MergeFailure::Conflicts(conflicted)))
+            }
+        },
+    }
+}
+
+/// Reset `worktree_path`'s index (and, unless `staged_only`, its working
+/// directory and untracked files) back to its branch HEAD. `staged_only`
+/// mirrors `git reset` (mixed): unstage everything but leave the working
+/// tree alone. Otherwise this is a `--hard` reset that also sweeps untracked
+/// files, since a leftover untracked file would defeat the point of
+/// resetting a messed-up worktree.
+#[cfg(feature = "libgit2")]
+pub fn reset_worktree(worktree_path: &Path, staged_only: bool) -> Result<()> {
+    let repo = git2::Repository::open(worktree_path).map_err(|e| Error::Git(e.to_string()))?;
+    let head_commit = repo
+        .head()
+        .and_then(|r| r.peel_to_commit())
+        .map_err(|e| Error::Git(e.to_string()))?;
+    let head_object = head_commit.as_object();
+
+    if staged_only {
+        repo.reset(head_object, git2::ResetType::Mixed, None)
+            .map_err(|e| Error::Git(e.to_string()))?;
+        return Ok(());
+    }
+
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.force().remove_untracked(true);
+    repo.reset(head_object, git2::ResetType::Hard, Some(&mut checkout))
+        .map_err(|e| Error::Git(e.to_string()))?;
+    Ok(())
+}
+
+#[cfg(not(feature = "libgit2"))]
+pub fn reset_worktree(_worktree_path: &Path, _staged_only: bool) -> Result<()> {
+    Err(Error::MissingDependency(
+        "libgit2 (rebuild wt with the `libgit2` feature to use `wt reset`)".to_string(),
+    ))
+}
+
+#[cfg(feature = "libgit2")]
+impl GitReadBackend for Git2Backend {
+    fn current_branch(&self, path: &Path) -> Result<String> {
+        let repo = git2::Repository::open(path).map_err(|e| Error::Git(e.to_string()))?;
+        let head = repo.head().map_err(|e| Error::Git(e.to_string()))?;
+        Ok(head.shorthand().unwrap_or("HEAD").to_string())
+    }
+
+    fn branch_exists(&self, branch: &str) -> Result<bool> {
+        Ok(self
+            .repo
+            .find_branch(branch, git2::BranchType::Local)
+            .is_ok()
+            || self
+                .repo
+                .find_branch(&format!("origin/{}", branch), git2::BranchType::Remote)
+                .is_ok())
+    }
+
+    fn list_worktrees(&self) -> Result<Vec<WorktreeInfo>> {
+        CliGitReadBackend.list_worktrees()
+    }
+
+    fn diff_stats(&self, path: &Path, base_branch: &str) -> Result<DiffStats> {
+        let repo = git2::Repository::open(path).map_err(|e| Error::Git(e.to_string()))?;
+        let base_tree = repo
+            .revparse_single(base_branch)
+            .and_then(|o| o.peel_to_tree())
+            .map_err(|e| Error::Git(e.to_string()))?;
+        let diff = repo
+            .diff_tree_to_workdir_with_index(Some(&base_tree), None)
+            .map_err(|e| Error::Git(e.to_string()))?;
+
+        let conflicted = repo
+            .index()
+            .map(|index| index.has_conflicts())
+            .unwrap_or(false);
+
+        let mut stats = DiffStats::default();
+        diff.foreach(
+            &mut |delta, _progress| {
+                let Some(path) = delta.new_file().path() else {
+                    return true;
+                };
+                let path = path.to_string_lossy().to_string();
+
+                // `delta.flags().is_binary()` mirrors the CLI backend's "-\t-"
+                // numstat sentinel; a conflicted index takes priority since
+                // conflict markers make any line count meaningless.
+                let error = if conflicted && repo.state() == git2::RepositoryState::Merge {
+                    Some(crate::worker::FileDiffError::Conflict)
+                } else if delta.flags().is_binary() {
+                    Some(crate::worker::FileDiffError::Binary)
+                } else {
+                    None
+                };
+
+                stats.files.push(crate::worker::FileDiff {
+                    path,
+                    insertions: 0,
+                    deletions: 0,
+                    error,
+                });
+                true
+            },
+            None,
+            None,
+            None,
+        )
+        .map_err(|e| Error::Git(e.to_string()))?;
+
+        let diff_stats = diff.stats().map_err(|e| Error::Git(e.to_string()))?;
+        stats.files_changed = diff_stats.files_changed();
+        stats.insertions = diff_stats.insertions();
+        stats.deletions = diff_stats.deletions();
+        Ok(stats)
+    }
+
+    fn commits_ahead(&self, path: &Path, base_branch: &str) -> Result<usize> {
+        let repo = git2::Repository::open(path).map_err(|e| Error::Git(e.to_string()))?;
+        let head = repo
+            .head()
+            .map_err(|e| Error::Git(e.to_string()))?
+            .target()
+            .ok_or_else(|| Error::Git("HEAD has no target".to_string()))?;
+        let base = repo
+            .revparse_single(base_branch)
+            .map_err(|e| Error::Git(e.to_string()))?
+            .id();
+        let (ahead, _behind) = repo
+            .graph_ahead_behind(head, base)
+            .map_err(|e| Error::Git(e.to_string()))?;
+        Ok(ahead)
+    }
+
+    fn status(&self, path: &Path) -> Result<Vec<StatusEntry>> {
+        let repo = git2::Repository::open(path).map_err(|e| Error::Git(e.to_string()))?;
+        let mut opts = git2::StatusOptions::new();
+        opts.include_untracked(true);
+        let statuses = repo
+            .statuses(Some(&mut opts))
+            .map_err(|e| Error::Git(e.to_string()))?;
+
+        let mut entries = Vec::new();
+        for entry in statuses.iter() {
+            let status = entry.status();
+            let path = entry.path().unwrap_or_default().to_string();
+
+            let staged = if status.intersects(git2::Status::INDEX_NEW) {
+                git::FileStatus::Added
+            } else if status.intersects(git2::Status::INDEX_MODIFIED) {
+                git::FileStatus::Modified
+            } else if status.intersects(git2::Status::INDEX_DELETED) {
+                git::FileStatus::Deleted
+            } else if status.intersects(git2::Status::INDEX_RENAMED) {
+                git::FileStatus::Renamed
+            } else {
+                git::FileStatus::Unmodified
+            };
+
+            let unstaged = if status.intersects(git2::Status::CONFLICTED) {
+                git::FileStatus::Conflicted
+            } else if status.intersects(git2::Status::WT_NEW) {
+                git::FileStatus::Untracked
+            } else if status.intersects(git2::Status::WT_MODIFIED) {
+                git::FileStatus::Modified
+            } else if status.intersects(git2::Status::WT_DELETED) {
+                git::FileStatus::Deleted
+            } else {
+                git::FileStatus::Unmodified
+            };
+
+            entries.push(StatusEntry {
+                path,
+                rename_from: None,
+                staged,
+                unstaged,
+            });
+        }
+
+        Ok(entries)
+    }
+
+    fn merge(&self, branch: &str) -> Result<()> {
+        CliGitReadBackend.merge(branch)
+    }
+}
+
+/// Pick the fastest available backend: `libgit2` when built with the
+/// `libgit2` feature and the repo can be opened, otherwise the CLI. Set
+/// `prefer_shell_git` (from `wt.toml`'s `prefer_shell_git`) to force the CLI
+/// path even when `libgit2` is available, for environments where the linked
+/// libgit2 is missing a feature `wt` needs.
+pub fn default_backend(repo_root: &Path, prefer_shell_git: bool) -> Box<dyn GitReadBackend> {
+    #[cfg(feature = "libgit2")]
+    {
+        if !prefer_shell_git {
+            if let Ok(backend) = Git2Backend::open(repo_root) {
+                return Box::new(backend);
+            }
+        }
+    }
+    let _ = (repo_root, prefer_shell_git);
+    Box::new(CliGitReadBackend)
+}
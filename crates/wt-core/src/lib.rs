@@ -6,19 +6,35 @@
 //! - Worker state management
 //! - Tmux session handling
 //! - Orchestrator state persistence
+//! - Pluggable VCS backends (git, jj)
+//! - Pluggable coding-agent adapters (claude, aider, ...)
 
+pub mod adapter;
 pub mod config;
 pub mod error;
 pub mod git;
+pub mod gitbackend;
+pub mod preflight;
 pub mod session;
 pub mod spawn;
 pub mod state;
+pub mod suggest;
+pub mod template;
 pub mod terminal;
+pub mod vcs;
 pub mod worker;
 pub mod worktree;
 
-pub use config::{Config, RepoConfig, WtToml};
-pub use error::{Error, Result};
-pub use state::OrchestratorState;
+pub use adapter::{Adapter, ConfiguredAdapter};
+pub use config::{
+    set_cli_config_overrides, AliasValue, Config, ConfigLayer, HookEvent, ProjectEntry, RepoConfig, WtToml,
+};
+pub use error::{Error, ErrorReport, Result};
+pub use gitbackend::{
+    default_backend, integrate_worktree, libgit2_version, CliGitReadBackend, GitReadBackend, MergeFailure,
+    MergeOutcome, Strategy,
+};
+pub use state::{format_age, Migrate, OpKind, OpLog, OpLogEntry, OrchestratorState};
+pub use vcs::{detect_backend, GitBackend, JjBackend, VcsBackend};
 pub use worker::{DiffStats, FileDiff, TaskContext, Worker, WorkerId, WorkerStatus};
 pub use worktree::Worktree;
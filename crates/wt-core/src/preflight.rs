@@ -0,0 +1,208 @@
+//! Dependency preflight checks
+//!
+//! [`terminal::check_dependencies`] reports presence/absence for `wt health
+//! --json`, but it doesn't check *versions*, and callers like `wt init` want
+//! to fail fast with everything the user needs to fix, not one tool at a
+//! time. [`check`] probes every required dependency up front and rolls every
+//! problem found into a single [`Error::MissingDependencies`] instead of
+//! stopping at the first.
+
+use std::process::Command;
+
+use crate::error::{Error, Result};
+use crate::terminal::command_exists;
+
+/// Whether a dependency is missing entirely or just older than `wt` needs.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DependencyProblem {
+    Absent,
+    TooOld,
+}
+
+/// A single problem found while preflighting `wt`'s dependencies.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DependencyIssue {
+    pub name: String,
+    pub problem: DependencyProblem,
+    pub detected_version: Option<String>,
+    pub required_version: String,
+}
+
+impl std::fmt::Display for DependencyIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.problem {
+            DependencyProblem::Absent => {
+                write!(
+                    f,
+                    "{} is not installed (requires >= {})",
+                    self.name, self.required_version
+                )
+            }
+            DependencyProblem::TooOld => write!(
+                f,
+                "{} is too old ({}, requires >= {})",
+                self.name,
+                self.detected_version
+                    .as_deref()
+                    .unwrap_or("unknown version"),
+                self.required_version,
+            ),
+        }
+    }
+}
+
+/// One dependency `wt` shells out to and the minimum version it needs.
+struct Requirement {
+    name: &'static str,
+    version_args: &'static [&'static str],
+    min_version: (u32, u32, u32),
+}
+
+const REQUIREMENTS: &[Requirement] = &[
+    Requirement {
+        name: "git",
+        version_args: &["--version"],
+        min_version: (2, 5, 0),
+    },
+    Requirement {
+        name: "tmux",
+        version_args: &["-V"],
+        min_version: (1, 8, 0),
+    },
+];
+
+/// Pull the first `major.minor[.patch]` triple out of a version string,
+/// tolerant of a leading command name (`git version 2.43.0`) and a trailing
+/// non-numeric suffix (`tmux 3.3a`).
+fn parse_version(text: &str) -> Option<(u32, u32, u32)> {
+    let token = text
+        .split_whitespace()
+        .find(|tok| tok.starts_with(|c: char| c.is_ascii_digit()))?;
+    let digits_only = |s: &str| {
+        s.chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>()
+    };
+    let mut parts = token.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts
+        .next()
+        .map(|s| digits_only(s))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+    let patch = parts
+        .next()
+        .map(|s| digits_only(s))
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+fn format_version((major, minor, patch): (u32, u32, u32)) -> String {
+    format!("{major}.{minor}.{patch}")
+}
+
+fn detect_version(req: &Requirement) -> Option<String> {
+    let output = Command::new(req.name)
+        .args(req.version_args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .map(str::to_string)
+}
+
+/// Probe every dependency in [`REQUIREMENTS`], returning `Ok(())` if all are
+/// present and new enough, or every problem found (not just the first) as
+/// [`Error::MissingDependencies`]. A version that can't be parsed is given
+/// the benefit of the doubt rather than reported as too old, matching
+/// [`terminal::check_dependencies`]'s best-effort treatment of unparsed
+/// `--version` output.
+pub fn check() -> Result<()> {
+    let issues: Vec<DependencyIssue> = REQUIREMENTS
+        .iter()
+        .filter_map(|req| {
+            if !command_exists(req.name) {
+                return Some(DependencyIssue {
+                    name: req.name.to_string(),
+                    problem: DependencyProblem::Absent,
+                    detected_version: None,
+                    required_version: format_version(req.min_version),
+                });
+            }
+
+            let detected = detect_version(req);
+            match detected.as_deref().and_then(parse_version) {
+                Some(version) if version >= req.min_version => None,
+                None => None,
+                Some(_) => Some(DependencyIssue {
+                    name: req.name.to_string(),
+                    problem: DependencyProblem::TooOld,
+                    detected_version: detected,
+                    required_version: format_version(req.min_version),
+                }),
+            }
+        })
+        .collect();
+
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::MissingDependencies(issues))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_git_version() {
+        assert_eq!(parse_version("git version 2.43.0"), Some((2, 43, 0)));
+    }
+
+    #[test]
+    fn parses_tmux_version_with_letter_suffix() {
+        assert_eq!(parse_version("tmux 3.3a"), Some((3, 3, 0)));
+    }
+
+    #[test]
+    fn parses_missing_patch_component() {
+        assert_eq!(parse_version("tmux 2.1"), Some((2, 1, 0)));
+    }
+
+    #[test]
+    fn rejects_unparseable_text() {
+        assert_eq!(parse_version("unknown"), None);
+    }
+
+    #[test]
+    fn display_bullets_absent_and_too_old() {
+        let absent = DependencyIssue {
+            name: "git".to_string(),
+            problem: DependencyProblem::Absent,
+            detected_version: None,
+            required_version: "2.5.0".to_string(),
+        };
+        assert_eq!(
+            absent.to_string(),
+            "git is not installed (requires >= 2.5.0)"
+        );
+
+        let too_old = DependencyIssue {
+            name: "tmux".to_string(),
+            problem: DependencyProblem::TooOld,
+            detected_version: Some("tmux 1.6".to_string()),
+            required_version: "1.8.0".to_string(),
+        };
+        assert_eq!(
+            too_old.to_string(),
+            "tmux is too old (tmux 1.6, requires >= 1.8.0)"
+        );
+    }
+}
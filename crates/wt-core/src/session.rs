@@ -7,6 +7,21 @@ use std::process::Command;
 
 use crate::error::{Error, Result};
 
+/// Run a tmux command, folding a non-zero exit into a descriptive
+/// [`Error::Tmux`] with the trimmed stderr instead of silently reporting
+/// success (e.g. a duplicate window name or a missing `-c` directory on
+/// `new-window`, or a dead tmux server).
+fn run_tmux(args: &[&str]) -> Result<()> {
+    let output = Command::new("tmux").args(args).output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(Error::Tmux(stderr));
+    }
+
+    Ok(())
+}
+
 /// Check if a tmux session exists
 pub fn session_exists(session: &str) -> bool {
     Command::new("tmux")
@@ -19,9 +34,7 @@ pub fn session_exists(session: &str) -> bool {
 /// Create a tmux session if it doesn't exist
 pub fn ensure_session(session: &str) -> Result<()> {
     if !session_exists(session) {
-        Command::new("tmux")
-            .args(["new-session", "-d", "-s", session])
-            .output()?;
+        run_tmux(&["new-session", "-d", "-s", session])?;
     }
     Ok(())
 }
@@ -45,38 +58,37 @@ pub fn window_exists(session: &str, window: &str) -> bool {
     }
 }
 
-/// Create a new window in a session
+/// Create a new window in a session. Fails fast with [`Error::DuplicateWindow`]
+/// if `window` already exists, since `list_windows`/`kill_window`/
+/// `window_exists` all key on name and a silent collision would leave two
+/// windows neither can disambiguate.
 pub fn create_window(session: &str, window: &str, dir: &Path) -> Result<()> {
     ensure_session(session)?;
 
-    Command::new("tmux")
-        .args([
-            "new-window",
-            "-t",
-            session,
-            "-n",
-            window,
-            "-c",
-            &dir.to_string_lossy(),
-        ])
-        .output()?;
+    if window_exists(session, window) {
+        return Err(Error::DuplicateWindow(window.to_string()));
+    }
 
-    Ok(())
+    run_tmux(&[
+        "new-window",
+        "-t",
+        session,
+        "-n",
+        window,
+        "-c",
+        &dir.to_string_lossy(),
+    ])
 }
 
 /// Send keys to a window
 pub fn send_keys(session: &str, window: &str, keys: &str) -> Result<()> {
-    Command::new("tmux")
-        .args([
-            "send-keys",
-            "-t",
-            &format!("{}:{}", session, window),
-            keys,
-            "Enter",
-        ])
-        .output()?;
-
-    Ok(())
+    run_tmux(&[
+        "send-keys",
+        "-t",
+        &format!("{}:{}", session, window),
+        keys,
+        "Enter",
+    ])
 }
 
 /// Kill a window
@@ -85,35 +97,73 @@ pub fn kill_window(session: &str, window: &str) -> Result<()> {
         return Ok(());
     }
 
-    Command::new("tmux")
-        .args(["kill-window", "-t", &format!("{}:{}", session, window)])
-        .output()?;
-
-    Ok(())
+    run_tmux(&["kill-window", "-t", &format!("{}:{}", session, window)])
 }
 
 /// Select a window
 pub fn select_window(session: &str, window: &str) -> Result<()> {
-    Command::new("tmux")
-        .args(["select-window", "-t", &format!("{}:{}", session, window)])
-        .output()?;
+    run_tmux(&["select-window", "-t", &format!("{}:{}", session, window)])
+}
 
-    Ok(())
+/// Name of the session the current client is attached to, or `None` if this
+/// process isn't running inside a tmux client (or tmux can't be reached).
+pub fn current_session_name() -> Option<String> {
+    let output = Command::new("tmux")
+        .args(["display-message", "-p", "#S"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Switch the current client to `session`, instead of `attach`'s
+/// `attach-session` — used when already inside tmux (`$TMUX` set), where
+/// `attach-session` would otherwise nest a session inside itself rather than
+/// just moving the existing client over.
+pub fn switch_client(session: &str) -> Result<()> {
+    run_tmux(&["switch-client", "-t", session])
+}
+
+/// Build the `tmux attach-session` argv for a session, applying the
+/// read-only (`-r`) and detach-others (`-d`) flags. The two combine freely —
+/// `-r -d` takes over a session someone else has open and watches it
+/// without being able to drive it, the safest way to peek at an autonomous
+/// worker mid-run.
+fn attach_args<'a>(session: &'a str, read_only: bool, detach_others: bool) -> Vec<&'a str> {
+    let mut args = vec!["attach-session", "-t", session];
+    if read_only {
+        args.push("-r");
+    }
+    if detach_others {
+        args.push("-d");
+    }
+    args
 }
 
 /// Attach to a session (replaces current process on Unix)
 #[cfg(unix)]
-pub fn attach(session: &str) -> Result<()> {
+pub fn attach(session: &str, read_only: bool, detach_others: bool) -> Result<()> {
     if !session_exists(session) {
         return Err(Error::TmuxSessionNotFound(session.to_string()));
     }
 
     use std::ffi::CString;
 
+    let argv = attach_args(session, read_only, detach_others);
+
     let cmd = CString::new("tmux").unwrap();
-    let args: Vec<CString> = ["tmux", "attach", "-t", session]
-        .iter()
-        .map(|a| CString::new(*a).unwrap())
+    let args: Vec<CString> = std::iter::once("tmux")
+        .chain(argv)
+        .map(|a| CString::new(a).unwrap())
         .collect();
     let args: Vec<&std::ffi::CStr> = args.iter().map(|a| a.as_c_str()).collect();
 
@@ -122,14 +172,14 @@ pub fn attach(session: &str) -> Result<()> {
 }
 
 #[cfg(not(unix))]
-pub fn attach(session: &str) -> Result<()> {
+pub fn attach(session: &str, read_only: bool, detach_others: bool) -> Result<()> {
     if !session_exists(session) {
         return Err(Error::TmuxSessionNotFound(session.to_string()));
     }
 
     // On non-Unix, just run tmux attach as a subprocess
     let status = Command::new("tmux")
-        .args(["attach", "-t", session])
+        .args(attach_args(session, read_only, detach_others))
         .status()?;
 
     if !status.success() {
@@ -190,6 +240,60 @@ pub fn get_pane_command(session: &str, window: &str) -> Option<String> {
     }
 }
 
+/// Capture a window's pane buffer via `tmux capture-pane -p`, joining
+/// wrapped lines (`-J`) so a long line split across the pane width reads
+/// back as one line. `lines` pulls that many lines of scrollback history
+/// (`-S -N`) in addition to the visible pane; `None` captures only what's
+/// currently on screen.
+pub fn capture_pane(session: &str, window: &str, lines: Option<usize>) -> Result<String> {
+    if !window_exists(session, window) {
+        return Err(Error::WorkerNotFound {
+            name: window.to_string(),
+            candidates: list_windows(session).unwrap_or_default(),
+        });
+    }
+
+    let target = format!("{}:{}", session, window);
+    let start = lines.map(|n| format!("-{}", n));
+
+    let mut args = vec!["capture-pane", "-p", "-J", "-t", &target];
+    if let Some(start) = &start {
+        args.push("-S");
+        args.push(start);
+    }
+
+    let output = Command::new("tmux").args(&args).output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(Error::Tmux(stderr));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Name of the currently active window in a session, if any (e.g. for
+/// `wt attach -` to know what "current" means before it swaps away from it).
+pub fn current_window(session: &str) -> Option<String> {
+    if !session_exists(session) {
+        return None;
+    }
+
+    let output = Command::new("tmux")
+        .args([
+            "list-windows",
+            "-t",
+            session,
+            "-F",
+            "#{window_active} #{window_name}",
+        ])
+        .output()
+        .ok()?;
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    text.lines()
+        .find_map(|line| line.strip_prefix("1 ").map(|name| name.to_string()))
+}
+
 /// List all windows in a session
 pub fn list_windows(session: &str) -> Result<Vec<String>> {
     if !session_exists(session) {
@@ -203,3 +307,34 @@ pub fn list_windows(session: &str) -> Result<Vec<String>> {
     let text = String::from_utf8_lossy(&output.stdout);
     Ok(text.lines().map(|s| s.to_string()).collect())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attach_args_plain() {
+        assert_eq!(
+            attach_args("wt-demo", false, false),
+            vec!["attach-session", "-t", "wt-demo"]
+        );
+    }
+
+    #[test]
+    fn attach_args_combine_read_only_and_detach_others() {
+        assert_eq!(
+            attach_args("wt-demo", true, true),
+            vec!["attach-session", "-t", "wt-demo", "-r", "-d"]
+        );
+    }
+
+    #[test]
+    fn run_tmux_surfaces_stderr_on_failure() {
+        let err = run_tmux(&["kill-session", "-t", "wt-session-that-does-not-exist"])
+            .expect_err("killing a nonexistent session should fail");
+        match err {
+            Error::Tmux(message) => assert!(!message.is_empty()),
+            other => panic!("expected Error::Tmux, got {other:?}"),
+        }
+    }
+}
@@ -2,13 +2,15 @@
 //!
 //! High-level operations for spawning and managing workers.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
-use crate::config::{get_base_branch, RepoConfig};
+use crate::adapter::Adapter;
+use crate::config::{get_base_branch, Config, RemoteConfig, RemoteRepo, RepoConfig, WtToml};
 use crate::error::{Error, Result};
 use crate::git;
 use crate::session;
-use crate::state::OrchestratorState;
+use crate::state::{OpKind, OrchestratorState};
 use crate::worker::{TaskContext, Worker, WorkerStatus};
 
 /// Task status for ps command
@@ -38,11 +40,106 @@ pub struct TaskInfo {
     pub status: TaskStatus,
     pub branch: String,
     pub commits_ahead: usize,
-    pub is_dirty: bool,
+    /// Compact per-file status summary, e.g. "3 staged, 1 modified" or "clean".
+    pub status_summary: String,
+    /// Ahead/behind counts and per-category file counts, for `ps`'s compact
+    /// prompt-style status column.
+    pub git_status: git::RichStatus,
+    /// Name of the repo this task was spawned in: the repo directory's name
+    /// for the repo `wt` was invoked from, or a `[projects.<name>]` key for
+    /// a polyrepo spawn target.
+    pub repo: String,
+    /// Whether this worker's window is the one currently attached, or the
+    /// one `wt attach -` would toggle back to.
+    pub recency: WindowRecency,
+    /// User-defined tags, for `--tag`-filtered bulk operations.
+    pub tags: Vec<String>,
 }
 
-/// Get the tmux session name for this repo
+/// Marks the current/previous worker window in `wt ps`'s output, so the
+/// `wt attach -` toggle target is visible without inspecting state by hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindowRecency {
+    Current,
+    Previous,
+    #[default]
+    Other,
+}
+
+fn window_recency(name: &str, live_current: Option<&str>, previous: Option<&str>) -> WindowRecency {
+    if live_current == Some(name) {
+        WindowRecency::Current
+    } else if previous == Some(name) {
+        WindowRecency::Previous
+    } else {
+        WindowRecency::Other
+    }
+}
+
+/// Resolve which of `toml`'s `[projects]` a spawn should also target, by
+/// glob-style name filters. `include` selects the subset to target (`None`
+/// targets none — a polyrepo spawn is opt-in); `exclude` then drops any
+/// names it matches, even ones `include` selected.
+pub fn select_projects(toml: &WtToml, include: Option<&str>, exclude: Option<&str>) -> Vec<String> {
+    let mut names: Vec<String> = match include {
+        Some(pattern) => match glob::Pattern::new(pattern) {
+            Ok(pat) => toml
+                .projects
+                .keys()
+                .filter(|name| pat.matches(name))
+                .cloned()
+                .collect(),
+            Err(_) => Vec::new(),
+        },
+        None => Vec::new(),
+    };
+
+    if let Some(pattern) = exclude {
+        if let Ok(pat) = glob::Pattern::new(pattern) {
+            names.retain(|name| !pat.matches(name));
+        }
+    }
+
+    names.sort();
+    names
+}
+
+/// Materialize `name`'s repository locally: clone it into its managed
+/// location (honoring the configured branch) on first use, or reuse the
+/// clone that's already there.
+pub fn ensure_project_repo(name: &str, repo: &RemoteRepo) -> Result<PathBuf> {
+    let path = Config::managed_project_dir(name)?;
+    git::clone_repo(&repo.url, &path, repo.branch.as_deref())?;
+    Ok(path)
+}
+
+/// Run `f` with the process's current directory temporarily switched to
+/// `repo_path`, restoring the original directory afterward (even on error).
+/// The rest of `wt-core` resolves "the repo" from the process cwd, so this
+/// is how a polyrepo spawn reuses that single-repo machinery for another
+/// repo without threading an explicit root through every call.
+pub fn with_repo_cwd<T, E>(repo_path: &Path, f: impl FnOnce() -> std::result::Result<T, E>) -> std::result::Result<T, E>
+where
+    E: From<std::io::Error>,
+{
+    let previous = std::env::current_dir()?;
+    std::env::set_current_dir(repo_path)?;
+    let result = f();
+    std::env::set_current_dir(previous)?;
+    result
+}
+
+/// Get the tmux session name for this repo. Honors `WT_SESSION_NAME` first,
+/// so two clones of a same-named directory (e.g. two `worktree-manager`
+/// checkouts) don't collide into one shared tmux session; falls back to
+/// `wt-<repo-dir>` otherwise.
 pub fn get_session_name() -> Result<String> {
+    if let Ok(name) = std::env::var("WT_SESSION_NAME") {
+        if !name.is_empty() {
+            return Ok(name);
+        }
+    }
+
     let repo_root = git::get_base_repo()?;
     let name = repo_root
         .file_name()
@@ -51,8 +148,42 @@ pub fn get_session_name() -> Result<String> {
     Ok(format!("wt-{}", name))
 }
 
+/// Check whether a spawn of `name` would collide with an existing, still-live
+/// task: either an active worker in `spawn_state.tasks`, or a live tmux
+/// window left over from one. Spawning over either would silently clobber
+/// state or the running process, so callers must check this first.
+///
+/// With `force`, a collision is resolved rather than rejected: the stale
+/// tmux window is killed (via [`kill_window`]) so the caller is free to
+/// re-register `name` from scratch instead of quietly sharing state with it.
+pub fn check_no_collision(name: &str, force: bool) -> Result<()> {
+    let repo_root = git::get_base_repo()?;
+    let session_name = get_session_name()?;
+
+    let state_collision = OrchestratorState::load(&repo_root)?
+        .and_then(|state| state.get_worker_by_name(name).map(|w| w.is_active()))
+        .unwrap_or(false);
+    let window_collision = session::window_exists(&session_name, name);
+
+    if !state_collision && !window_collision {
+        return Ok(());
+    }
+
+    if !force {
+        return Err(Error::DuplicateSpawn(name.to_string()));
+    }
+
+    if window_collision {
+        kill_window(name)?;
+    }
+
+    Ok(())
+}
+
 /// Register a new spawn (creates worker state)
 pub fn register(name: &str, branch: &str, context: Option<&str>) -> Result<()> {
+    check_no_collision(name, false)?;
+
     let repo_root = git::get_base_repo()?;
     let worktrees_dir = git::get_worktrees_dir()?;
     let worktree_path = worktrees_dir.join(name);
@@ -81,34 +212,26 @@ pub fn register(name: &str, branch: &str, context: Option<&str>) -> Result<()> {
 
     worker.tmux_window = Some(name.to_string());
     state.add_worker(worker);
-    state.save()?;
+    state.save_with_log(OpKind::Create, name, &format!("spawned '{}'", name))?;
 
     Ok(())
 }
 
-/// Launch a tmux window for a worker
+/// Launch a tmux window for a worker, driving the agent described by `adapter`.
 pub fn launch_tmux_window(
     name: &str,
     worktree_path: &Path,
     auto: bool,
     context: Option<&str>,
+    adapter: &dyn Adapter,
 ) -> Result<()> {
     let session_name = get_session_name()?;
 
     // Create window in tmux
     session::create_window(&session_name, name, worktree_path)?;
 
-    // Build claude command
-    let mut cmd = "claude".to_string();
-    if let Some(ctx) = context {
-        // Escape single quotes in context
-        let escaped = ctx.replace('\'', "'\\''");
-        cmd = format!("claude '{}'", escaped);
-    }
-
-    if auto {
-        cmd.push_str(" --dangerously-skip-permissions");
-    }
+    // Build the agent command from the adapter's template
+    let cmd = adapter.build_command(context, auto, worktree_path)?;
 
     // Send command to window
     session::send_keys(&session_name, name, &cmd)?;
@@ -116,40 +239,78 @@ pub fn launch_tmux_window(
     Ok(())
 }
 
-/// List all tasks (workers) with their status
+/// List all tasks (workers) with their status, in the current repo.
 pub fn list_tasks() -> Result<Vec<TaskInfo>> {
     let repo_root = git::get_base_repo()?;
+    let repo_label = repo_root
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("repo")
+        .to_string();
     let session_name = get_session_name()?;
     let base_branch = get_base_branch()?;
     let worktrees_dir = git::get_worktrees_dir()?;
+    // Go through the configured VcsBackend (git or jj) rather than calling
+    // `git::` directly, so `wt ps` reports correctly for either.
+    let backend = crate::vcs::detect_backend(&repo_root, &worktrees_dir);
 
     // Try to load state
     let state = OrchestratorState::load(&repo_root)?;
+    let live_current = session::current_window(&session_name);
+    let previous_window = state.as_ref().and_then(|s| s.previous_window.clone());
 
     let mut tasks = Vec::new();
 
     // If we have state, use workers from state
     if let Some(state) = state {
+        // Batch the per-worktree `git status`/rich-status computation across
+        // all workers up front instead of serially inside the loop below, so
+        // `wt ps` on a repo with many workers doesn't stall on the slowest
+        // one.
+        let existing_paths: Vec<PathBuf> = state
+            .workers
+            .values()
+            .map(|w| worktrees_dir.join(&w.name))
+            .filter(|p| p.exists())
+            .collect();
+        let statuses: std::collections::HashMap<PathBuf, git::StatusSummary> =
+            git::refresh_all_statuses(&existing_paths, &base_branch, git::default_batch_size())
+                .collect();
+
         for worker in state.workers.values() {
             let status = get_worker_status(&session_name, &worker.name);
             let worktree_path = worktrees_dir.join(&worker.name);
 
-            let (commits_ahead, is_dirty) = if worktree_path.exists() {
-                let commits = git::get_commits_ahead(&worktree_path, &base_branch)
+            let (commits_ahead, status_summary, git_status) = if worktree_path.exists() {
+                let commits = backend
+                    .commits_ahead(&worker.name, &base_branch)
                     .unwrap_or_default()
                     .len();
-                let dirty = git::has_uncommitted_changes(&worktree_path).unwrap_or(false);
-                (commits, dirty)
+                let summary = statuses
+                    .get(&worktree_path)
+                    .map(|s| s.summary.clone())
+                    .unwrap_or_else(|| "unknown".to_string());
+                let rich = statuses
+                    .get(&worktree_path)
+                    .map(|s| s.rich.clone())
+                    .unwrap_or_default();
+                (commits, summary, rich)
             } else {
-                (0, false)
+                (0, "clean".to_string(), git::RichStatus::default())
             };
 
+            let recency = window_recency(&worker.name, live_current.as_deref(), previous_window.as_deref());
+
             tasks.push(TaskInfo {
                 name: worker.name.clone(),
                 status,
                 branch: worker.branch.clone(),
                 commits_ahead,
-                is_dirty,
+                status_summary,
+                git_status,
+                repo: repo_label.clone(),
+                recency,
+                tags: worker.tags.clone(),
             });
         }
     } else {
@@ -163,19 +324,28 @@ pub fn list_tasks() -> Result<Vec<TaskInfo>> {
             }
 
             let status = get_worker_status(&session_name, &window_name);
-            let branch = git::get_worktree_branch(&worktree_path).unwrap_or_default();
+            let branch = backend.worktree_branch(&window_name).unwrap_or_default();
 
-            let commits_ahead = git::get_commits_ahead(&worktree_path, &base_branch)
+            let commits_ahead = backend
+                .commits_ahead(&window_name, &base_branch)
                 .unwrap_or_default()
                 .len();
-            let is_dirty = git::has_uncommitted_changes(&worktree_path).unwrap_or(false);
+            let status_summary = git::get_status(&worktree_path)
+                .map(|entries| git::summarize_status(&entries))
+                .unwrap_or_else(|_| "unknown".to_string());
+            let git_status = git::get_rich_status(&worktree_path, &base_branch).unwrap_or_default();
+            let recency = window_recency(&window_name, live_current.as_deref(), previous_window.as_deref());
 
             tasks.push(TaskInfo {
                 name: window_name,
                 status,
                 branch,
                 commits_ahead,
-                is_dirty,
+                status_summary,
+                git_status,
+                repo: repo_label.clone(),
+                recency,
+                tags: Vec::new(),
             });
         }
     }
@@ -183,6 +353,63 @@ pub fn list_tasks() -> Result<Vec<TaskInfo>> {
     Ok(tasks)
 }
 
+/// List tasks across the current repo and every `[projects]` entry that's
+/// already been cloned locally, so `wt ps` can report a polyrepo spawn's
+/// sessions grouped by repo. Projects never cloned (no spawn has targeted
+/// them yet) have nothing to report and are skipped.
+pub fn list_all_tasks(toml: &WtToml) -> Result<Vec<TaskInfo>> {
+    let mut tasks = list_tasks()?;
+
+    let mut names: Vec<&String> = toml.projects.keys().collect();
+    names.sort();
+
+    for name in names {
+        let path = match Config::managed_project_dir(name) {
+            Ok(path) => path,
+            Err(_) => continue,
+        };
+        if !path.exists() {
+            continue;
+        }
+
+        let project_tasks = with_repo_cwd(&path, list_tasks).unwrap_or_default();
+        tasks.extend(project_tasks.into_iter().map(|mut task| {
+            task.repo = name.clone();
+            task
+        }));
+    }
+
+    Ok(tasks)
+}
+
+/// List just the names of current tasks (workers, or tmux windows when
+/// there's no state file), optionally filtered to ones starting with
+/// `prefix`. Skips the git/tmux status lookups `list_tasks` does, so it's
+/// fast and scriptable — `wt ps -q` feeds this to shell completion for
+/// commands that take a worker name.
+pub fn list_task_names(prefix: Option<&str>) -> Result<Vec<String>> {
+    let repo_root = git::get_base_repo()?;
+    let worktrees_dir = git::get_worktrees_dir()?;
+    let session_name = get_session_name()?;
+
+    let mut names: Vec<String> = match OrchestratorState::load(&repo_root)? {
+        Some(state) => state.workers.values().map(|w| w.name.clone()).collect(),
+        None => session::list_windows(&session_name)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|name| worktrees_dir.join(name).exists())
+            .collect(),
+    };
+
+    if let Some(prefix) = prefix {
+        names.retain(|name| name.starts_with(prefix));
+    }
+
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
 fn get_worker_status(session: &str, window: &str) -> TaskStatus {
     if !session::session_exists(session) {
         return TaskStatus::NoSession;
@@ -199,20 +426,200 @@ fn get_worker_status(session: &str, window: &str) -> TaskStatus {
     }
 }
 
-/// Attach to tmux session
-pub fn attach(name: Option<&str>) -> Result<()> {
+/// Sentinel accepted in place of a worker name, toggling back to whichever
+/// window was current before the most recent `attach` — the `cd -`/`git
+/// checkout -` convention.
+pub const PREVIOUS_WINDOW: &str = "-";
+
+/// Attach to the repo's tmux session, optionally switching to a named window
+/// first. `read_only` maps to tmux's `attach-session -r` (view without being
+/// able to drive the pane); `detach_others` maps to `-d` (kick out any other
+/// client already attached). Window targeting is a `select-window` issued
+/// ahead of the attach rather than a `session:window` target passed to
+/// `attach-session` itself, since the latter doesn't affect which window a
+/// client already attached to the session lands on.
+///
+/// `name` is resolved as:
+/// - `Some("-")` ([`PREVIOUS_WINDOW`]) — toggle to the previously attached
+///   window.
+/// - `Some(window)` — select that window by name.
+/// - `None` while already inside this session (`$TMUX` set) — jump to the
+///   most recently attached-to worker, since there's nothing left to attach
+///   to and sitting still would be a no-op.
+/// - `None` otherwise — attach to the session as a whole and land on
+///   whatever window tmux last left active there.
+pub fn attach(name: Option<&str>, read_only: bool, detach_others: bool) -> Result<()> {
     let session_name = get_session_name()?;
 
-    // If a specific window is requested, select it first
-    if let Some(window) = name {
-        if !session::window_exists(&session_name, window) {
-            return Err(Error::WorkerNotFound(window.to_string()));
+    if let Ok(toml) = crate::config::read_layered_wt_toml(None) {
+        if let Some(remote) = toml.remote.as_ref() {
+            let window = match name {
+                Some(PREVIOUS_WINDOW) | None => None,
+                Some(window) => Some(window),
+            };
+            return attach_remote(remote, &session_name, window, read_only, detach_others);
         }
-        session::select_window(&session_name, window)?;
     }
 
-    // Attach to session
-    session::attach(&session_name)
+    if !session::session_exists(&session_name) {
+        return Err(Error::TmuxSessionNotFound(session_name));
+    }
+
+    let repo_root = git::get_base_repo()?;
+    let mut state = OrchestratorState::load(&repo_root)?;
+    let live_current = session::current_window(&session_name);
+
+    let target = match name {
+        Some(PREVIOUS_WINDOW) => state
+            .as_ref()
+            .and_then(|s| s.previous_window.clone())
+            .ok_or_else(|| Error::Custom("no previous worker to toggle back to".to_string()))?,
+        Some(window) => window.to_string(),
+        None if std::env::var_os("TMUX").is_some() => match state
+            .as_ref()
+            .and_then(|s| s.previous_window.clone())
+        {
+            Some(window) => window,
+            None => return session::attach(&session_name, read_only, detach_others),
+        },
+        None => return session::attach(&session_name, read_only, detach_others),
+    };
+
+    if !session::window_exists(&session_name, &target) {
+        return Err(Error::WorkerNotFound {
+            candidates: session::list_windows(&session_name).unwrap_or_default(),
+            name: target,
+        });
+    }
+
+    // Running inside tmux already (`$TMUX` set): attach-session would nest a
+    // session inside itself, so switch the existing client over instead. If
+    // it's already attached to this exact session and window there's
+    // nothing to do — refuse the redundant switch rather than flicker the
+    // client sideways onto itself.
+    if std::env::var_os("TMUX").is_some() {
+        let already_here = session::current_session_name().as_deref() == Some(session_name.as_str())
+            && live_current.as_deref() == Some(target.as_str());
+
+        if already_here {
+            return Ok(());
+        }
+
+        session::select_window(&session_name, &target)?;
+        if let Some(state) = &mut state {
+            state.note_attached(live_current.as_deref(), &target);
+            state.save()?;
+        }
+        return session::switch_client(&session_name);
+    }
+
+    session::select_window(&session_name, &target)?;
+
+    if let Some(state) = &mut state {
+        state.note_attached(live_current.as_deref(), &target);
+        state.save()?;
+    }
+
+    session::attach(&session_name, read_only, detach_others)
+}
+
+/// Quote `s` as a single POSIX shell word, for a string handed to `ssh` as
+/// a remote command line rather than an argv array — `ssh` ships that
+/// string to the remote sshd, which runs it through the login shell, so a
+/// session/window name with a space or shell metacharacter would otherwise
+/// be re-split or interpreted remotely instead of passed through verbatim.
+fn shell_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Attach to a `[remote]`-hosted repo's tmux session over SSH instead of
+/// attaching locally — worktree/branch bookkeeping for a remote-backed repo
+/// still happens on this machine, but the tmux session itself lives on
+/// `remote.host`. Replaces the current process on Unix, same as
+/// [`session::attach`]; `-t` forces a PTY so tmux renders correctly over the
+/// link.
+fn attach_remote(
+    remote: &RemoteConfig,
+    session_name: &str,
+    window: Option<&str>,
+    read_only: bool,
+    detach_others: bool,
+) -> Result<()> {
+    let target = match window {
+        Some(window) => format!("{}:{}", session_name, window),
+        None => session_name.to_string(),
+    };
+    let mut remote_cmd = format!("tmux attach -t {}", shell_quote(&target));
+    if read_only {
+        remote_cmd.push_str(" -r");
+    }
+    if detach_others {
+        remote_cmd.push_str(" -d");
+    }
+
+    #[cfg(unix)]
+    {
+        use std::ffi::CString;
+
+        let argv = ["ssh", "-t", remote.host.as_str(), remote_cmd.as_str()];
+        let cmd = CString::new("ssh").unwrap();
+        let args: Vec<CString> = argv.iter().map(|a| CString::new(*a).unwrap()).collect();
+        let args: Vec<&std::ffi::CStr> = args.iter().map(|a| a.as_c_str()).collect();
+
+        let err = nix::unistd::execvp(&cmd, &args);
+        Err(Error::Custom(format!("Failed to ssh attach to {}: {:?}", remote.host, err)))
+    }
+
+    #[cfg(not(unix))]
+    {
+        let status = Command::new("ssh").args(["-t", &remote.host, &remote_cmd]).status()?;
+        if !status.success() {
+            return Err(Error::Custom(format!("Failed to ssh attach to {}", remote.host)));
+        }
+        Ok(())
+    }
+}
+
+/// Select a worker's window without attaching a client to the session —
+/// `wt switch`'s bare `select-window`, for bouncing between two workers
+/// from inside an already-attached terminal instead of spawning a new
+/// client the way `attach` does. `name` resolves the same as `attach`'s
+/// `Some("-")`/`None` cases: both jump to the previously-focused window,
+/// since there's no "attach to the session as a whole" fallback here to
+/// fall back to.
+pub fn switch(name: Option<&str>) -> Result<()> {
+    let session_name = get_session_name()?;
+
+    if !session::session_exists(&session_name) {
+        return Err(Error::TmuxSessionNotFound(session_name));
+    }
+
+    let repo_root = git::get_base_repo()?;
+    let mut state = OrchestratorState::load(&repo_root)?;
+    let live_current = session::current_window(&session_name);
+
+    let target = match name {
+        Some(PREVIOUS_WINDOW) | None => state
+            .as_ref()
+            .and_then(|s| s.previous_window.clone())
+            .ok_or_else(|| Error::Custom("no previous worker to switch to".to_string()))?,
+        Some(window) => window.to_string(),
+    };
+
+    if !session::window_exists(&session_name, &target) {
+        return Err(Error::WorkerNotFound {
+            candidates: session::list_windows(&session_name).unwrap_or_default(),
+            name: target,
+        });
+    }
+    session::select_window(&session_name, &target)?;
+
+    if let Some(state) = &mut state {
+        state.note_attached(live_current.as_deref(), &target);
+        state.save()?;
+    }
+
+    Ok(())
 }
 
 /// Kill a worker's tmux window
@@ -226,8 +633,10 @@ pub fn kill(name: &str) -> Result<()> {
     // Update state if it exists
     if let Some(mut state) = OrchestratorState::load(&repo_root)? {
         if let Some(worker) = state.get_worker_by_name_mut(name) {
+            stop_services(&worker.service_pids);
+            worker.service_pids.clear();
             worker.status = WorkerStatus::Archived;
-            state.save()?;
+            state.save_with_log(OpKind::Kill, name, &format!("killed '{}'", name))?;
         }
     }
 
@@ -241,16 +650,160 @@ pub fn kill_window(name: &str) -> Result<()> {
     Ok(())
 }
 
-/// Unregister a worker from state
+/// Capture a worker's pane output, `lines` of scrollback deep in addition
+/// to what's currently on screen.
+pub fn logs(name: &str, lines: Option<usize>) -> Result<String> {
+    let session_name = get_session_name()?;
+    session::capture_pane(&session_name, name, lines)
+}
+
+/// Launch a worktree's background services as detached subprocesses,
+/// returning their PIDs so they can be torn down later.
+pub fn launch_services(worktree_path: &Path, services: &[String]) -> Result<Vec<u32>> {
+    let mut pids = Vec::new();
+
+    for service in services {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", service])
+            .current_dir(worktree_path)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        // Start the service in its own process group so `stop_services` can
+        // kill everything it forked, not just `sh` itself — a service that
+        // doesn't `exec` straight through (`npm run dev`, `cargo watch`,
+        // most dev-server wrappers) would otherwise leave its real worker
+        // process orphaned when only the shell gets signaled.
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+        }
+
+        let child = cmd.spawn()?;
+        pids.push(child.id());
+    }
+
+    Ok(pids)
+}
+
+/// Stop background services previously started with [`launch_services`].
+pub fn stop_services(pids: &[u32]) {
+    for pid in pids {
+        #[cfg(unix)]
+        {
+            use nix::sys::signal::{self, Signal};
+            use nix::unistd::Pid;
+            // Negative PID signals the whole process group `launch_services`
+            // placed the service in, reaching any children it forked
+            // instead of just the immediate `sh` process.
+            let _ = signal::kill(Pid::from_raw(-(*pid as i32)), Signal::SIGTERM);
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = Command::new("taskkill")
+                .args(["/PID", &pid.to_string(), "/F", "/T"])
+                .output();
+        }
+    }
+}
+
+/// Unregister a worker from state, tearing down any background services
 pub fn unregister(name: &str) -> Result<()> {
     let repo_root = git::get_base_repo()?;
 
     if let Some(mut state) = OrchestratorState::load(&repo_root)? {
         if let Some(worker) = state.get_worker_by_name_mut(name) {
+            stop_services(&worker.service_pids);
+            worker.service_pids.clear();
             worker.set_status(WorkerStatus::Archived);
+            state.save_with_log(OpKind::Remove, name, &format!("removed '{}'", name))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Add `tag` to a worker's label set, for bulk `--tag`-filtered operations.
+pub fn tag(name: &str, tag: &str) -> Result<()> {
+    let repo_root = git::get_base_repo()?;
+    let mut state = OrchestratorState::load(&repo_root)?.ok_or_else(|| Error::WorkerNotFound {
+        name: name.to_string(),
+        candidates: Vec::new(),
+    })?;
+
+    let candidates: Vec<String> = state.workers.values().map(|w| w.name.clone()).collect();
+    let worker = state
+        .get_worker_by_name_mut(name)
+        .ok_or_else(|| Error::WorkerNotFound { name: name.to_string(), candidates })?;
+    worker.add_tag(tag);
+
+    state.save_with_log(OpKind::ConfigChange, name, &format!("tagged '{}' with '{}'", name, tag))
+}
+
+/// Remove `tag` from a worker's label set.
+pub fn untag(name: &str, tag: &str) -> Result<()> {
+    let repo_root = git::get_base_repo()?;
+    let mut state = OrchestratorState::load(&repo_root)?.ok_or_else(|| Error::WorkerNotFound {
+        name: name.to_string(),
+        candidates: Vec::new(),
+    })?;
+
+    let candidates: Vec<String> = state.workers.values().map(|w| w.name.clone()).collect();
+    let worker = state
+        .get_worker_by_name_mut(name)
+        .ok_or_else(|| Error::WorkerNotFound { name: name.to_string(), candidates })?;
+    worker.remove_tag(tag);
+
+    state.save_with_log(OpKind::ConfigChange, name, &format!("untagged '{}' from '{}'", name, tag))
+}
+
+/// Every currently running/spawned task carrying `tag`, for bulk operations
+/// like `wt kill --tag`/`wt merge --tag`/`wt remove --tag` to iterate over.
+pub fn list_tasks_by_tag(tag: &str) -> Result<Vec<TaskInfo>> {
+    Ok(list_tasks()?
+        .into_iter()
+        .filter(|t| t.tags.iter().any(|t| t == tag))
+        .collect())
+}
+
+/// Record the PIDs of a worker's launched background services
+pub fn set_service_pids(name: &str, pids: Vec<u32>) -> Result<()> {
+    let repo_root = git::get_base_repo()?;
+
+    if let Some(mut state) = OrchestratorState::load(&repo_root)? {
+        if let Some(worker) = state.get_worker_by_name_mut(name) {
+            worker.service_pids = pids;
             state.save()?;
         }
     }
 
     Ok(())
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    // Exercises the actual fix rather than the SIGTERM/teardown path
+    // (timing- and scheduler-dependent to observe reliably): a service
+    // started by `launch_services` should be the leader of its own process
+    // group, since that's what lets `stop_services` reach a forked
+    // grandchild with one negative-PID signal instead of orphaning it.
+    #[test]
+    fn launch_services_starts_its_own_process_group() {
+        let pids =
+            launch_services(Path::new("."), &["sleep 5".to_string()]).expect("spawn sleep");
+
+        let pgid = nix::unistd::getpgid(Some(nix::unistd::Pid::from_raw(pids[0] as i32)))
+            .expect("getpgid");
+        assert_eq!(
+            pgid.as_raw(),
+            pids[0] as i32,
+            "service should be its own process group leader"
+        );
+
+        stop_services(&pids);
+    }
+}
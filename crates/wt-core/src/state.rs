@@ -4,7 +4,7 @@
 
 use crate::config::RepoConfig;
 use crate::error::Result;
-use crate::worker::{Worker, WorkerId};
+use crate::worker::{Worker, WorkerId, WorkerStatus};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -12,6 +12,45 @@ use std::path::{Path, PathBuf};
 /// Current state file version for migrations
 const STATE_VERSION: u32 = 1;
 
+/// A pure, version-to-version transformation applied to a state file's raw
+/// JSON before it's deserialized into its concrete struct. Implementors
+/// define `CURRENT_VERSION` and a `migrate` that walks the stored version up
+/// to it one step at a time, so a future field rename or restructure can add
+/// a step here without corrupting state files written by older releases (or
+/// ones with no `"version"` field at all, which are treated as version 0).
+pub trait Migrate: Sized {
+    const CURRENT_VERSION: u32;
+
+    /// Apply whatever migrations are needed to bring `value` from `from` up
+    /// to `Self::CURRENT_VERSION`, returning the migrated JSON.
+    fn migrate(value: serde_json::Value, from: u32) -> Result<serde_json::Value>;
+}
+
+impl Migrate for OrchestratorState {
+    const CURRENT_VERSION: u32 = STATE_VERSION;
+
+    fn migrate(mut value: serde_json::Value, from: u32) -> Result<serde_json::Value> {
+        let mut version = from;
+
+        // No migrations exist yet: STATE_VERSION has been 1 since
+        // `.wt-state.json` was introduced. When a future release needs to
+        // rename or restructure a field, add a step here, e.g.:
+        //   if version == 1 {
+        //       // rename `workers[].task` -> `workers[].task_context`
+        //       version = 2;
+        //   }
+        while version < Self::CURRENT_VERSION {
+            version += 1;
+        }
+
+        if let Some(obj) = value.as_object_mut() {
+            obj.insert("version".to_string(), serde_json::Value::from(version));
+        }
+
+        Ok(value)
+    }
+}
+
 /// Persistent orchestrator state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrchestratorState {
@@ -25,18 +64,32 @@ pub struct OrchestratorState {
     pub tmux_session: String,
     /// Repository configuration
     pub config: RepoConfig,
+    /// Name of the worker window `wt attach` last selected, before the one
+    /// selected most recently — tracked so `wt attach -` can toggle back to
+    /// it, tmux-shortener style.
+    #[serde(default)]
+    pub previous_window: Option<String>,
 }
 
 impl OrchestratorState {
-    /// Create a new orchestrator state
+    /// Create a new orchestrator state. The tmux session name honors
+    /// `WT_SESSION_NAME` first (so two clones of a same-named directory
+    /// don't get their worker windows merged into one session), falling
+    /// back to `wt-<repo-dir>` — same precedence as
+    /// [`crate::spawn::get_session_name`].
     pub fn new(repo_root: PathBuf, config: RepoConfig) -> Self {
-        let tmux_session = format!(
-            "wt-{}",
-            repo_root
-                .file_name()
-                .and_then(|n| n.to_str())
-                .unwrap_or("unknown")
-        );
+        let tmux_session = std::env::var("WT_SESSION_NAME")
+            .ok()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| {
+                format!(
+                    "wt-{}",
+                    repo_root
+                        .file_name()
+                        .and_then(|n| n.to_str())
+                        .unwrap_or("unknown")
+                )
+            });
 
         Self {
             version: STATE_VERSION,
@@ -44,6 +97,17 @@ impl OrchestratorState {
             workers: HashMap::new(),
             tmux_session,
             config,
+            previous_window: None,
+        }
+    }
+
+    /// Record `live_current` (the window tmux reports as active right before
+    /// this selection takes effect) as `previous_window` — unless it's the
+    /// same window being selected again, which would otherwise collapse
+    /// `wt attach -` into a no-op toggle.
+    pub fn note_attached(&mut self, live_current: Option<&str>, selecting: &str) {
+        if live_current != Some(selecting) {
+            self.previous_window = live_current.map(str::to_string);
         }
     }
 
@@ -61,14 +125,34 @@ impl OrchestratorState {
         }
 
         let content = std::fs::read_to_string(&state_file)?;
-        let state: Self = serde_json::from_str(&content)?;
+        let value: serde_json::Value = serde_json::from_str(&content)?;
+        let from_version = value
+            .get("version")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0) as u32;
+
+        if from_version > Self::CURRENT_VERSION {
+            return Err(crate::error::Error::StateTooNew {
+                found: from_version,
+                understood: Self::CURRENT_VERSION,
+            });
+        }
+
+        let needs_resave = from_version != Self::CURRENT_VERSION;
+        let value = Self::migrate(value, from_version)?;
+        let state: Self = serde_json::from_value(value)?;
 
-        // TODO: Handle migrations if version differs
+        if needs_resave {
+            state.save()?;
+        }
 
         Ok(Some(state))
     }
 
-    /// Save state to disk
+    /// Save state to disk, atomically: written to a sibling temp file first,
+    /// then renamed into place, so a crash mid-write (or a migration resave
+    /// racing a concurrent `wt` invocation) can never leave `.wt-state.json`
+    /// truncated or half-written.
     pub fn save(&self) -> Result<()> {
         let state_file = Self::state_file_path(&self.repo_root);
 
@@ -78,11 +162,34 @@ impl OrchestratorState {
         }
 
         let content = serde_json::to_string_pretty(self)?;
-        std::fs::write(&state_file, content)?;
+        let tmp_file = state_file.with_extension("json.tmp");
+        std::fs::write(&tmp_file, content)?;
+        std::fs::rename(&tmp_file, &state_file)?;
 
         Ok(())
     }
 
+    /// Save state to disk, first appending an op-log entry (under
+    /// `description`) snapshotting whatever was previously saved — so the
+    /// log can never diverge from the live state, every mutator should call
+    /// this instead of [`OrchestratorState::save`] directly.
+    ///
+    /// `kind`/`name` identify the worker the mutation acted on, so `wt undo`
+    /// can reverse it without having to diff two whole-state snapshots: the
+    /// worker's branch/base branch/path are pulled from whichever side of the
+    /// mutation still has them (the old state for a remove/kill, the new
+    /// state for a create).
+    pub fn save_with_log(&self, kind: OpKind, name: &str, description: &str) -> Result<()> {
+        if let Some(previous) = Self::load(&self.repo_root)? {
+            let worker = previous
+                .get_worker_by_name(name)
+                .or_else(|| self.get_worker_by_name(name));
+            OpLog::append(&self.repo_root, kind, name, worker, description, &previous)?;
+        }
+
+        self.save()
+    }
+
     /// Load or create state for a repository
     pub fn load_or_create(repo_root: PathBuf, config: RepoConfig) -> Result<Self> {
         match Self::load(&repo_root)? {
@@ -135,6 +242,195 @@ impl OrchestratorState {
     pub fn active_count(&self) -> usize {
         self.active_workers().count()
     }
+
+    /// Workers stuck in [`WorkerStatus::Running`] whose `updated_at` is
+    /// older than `threshold` — a supervisor watching many parallel agents
+    /// can use this to tell a silently-died worker apart from one that's
+    /// just not surfaced a review yet (`WaitingReview`) or is mid-approval
+    /// (`Approved`), neither of which this flags.
+    pub fn stale_workers(&self, threshold: chrono::Duration) -> Vec<&Worker> {
+        self.workers
+            .values()
+            .filter(|w| matches!(w.status, WorkerStatus::Running) && w.is_stale(threshold))
+            .collect()
+    }
+}
+
+/// Maximum number of entries kept in a repo's op-log before the oldest are
+/// pruned.
+const OPLOG_MAX_ENTRIES: usize = 50;
+
+/// Category of mutation recorded in the op-log. `Create`/`Remove`/`Kill`
+/// carry worktree metadata (name/branch/base branch/path) so `wt undo` can
+/// physically reverse them, not just restore JSON state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OpKind {
+    Create,
+    Remove,
+    Kill,
+    ConfigChange,
+}
+
+/// A single recorded mutation to [`OrchestratorState`]: a stable id, its
+/// kind, a human description, enough metadata to reverse it, and the full
+/// state snapshot as it was immediately before the mutation (the fallback
+/// `wt undo` restores even when there's no worktree to physically re-add).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpLogEntry {
+    /// Stable id, monotonically increasing; never reused even as older
+    /// entries are pruned, so `wt undo <id>` keeps working across rotation.
+    pub id: u64,
+    pub kind: OpKind,
+    /// Unix timestamp (seconds) the operation was recorded at.
+    pub timestamp: i64,
+    /// Human-readable description, e.g. "spawned 'feature-auth'".
+    pub description: String,
+    /// Worker name the operation acted on, if any.
+    pub worktree_name: Option<String>,
+    pub branch: Option<String>,
+    pub base_branch: Option<String>,
+    pub worktree_path: Option<PathBuf>,
+    /// State as it was immediately before this operation.
+    pub snapshot: OrchestratorState,
+}
+
+/// Append-only log of recent [`OrchestratorState`] mutations, stored next to
+/// `.wt-state.json` so `wt undo`/`wt oplog` never have to guess where a
+/// repo's history lives. [`OrchestratorState::save_with_log`] is the only
+/// way entries get appended, which keeps the log from ever diverging from
+/// the state file it shadows.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OpLog {
+    pub entries: Vec<OpLogEntry>,
+    /// Next id to assign. Kept separate from `entries.len()` so ids stay
+    /// stable and unique even after old entries are pruned.
+    #[serde(default)]
+    pub next_id: u64,
+}
+
+impl OpLog {
+    /// Get the op-log file path for a repository
+    pub fn log_file_path(repo_root: &Path) -> PathBuf {
+        repo_root.join(".worktrees").join(".wt-oplog.json")
+    }
+
+    /// Load the op-log from disk, or an empty one if it doesn't exist yet.
+    pub fn load(repo_root: &Path) -> Result<Self> {
+        let log_file = Self::log_file_path(repo_root);
+
+        if !log_file.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&log_file)?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    /// Save the op-log to disk.
+    pub fn save(&self, repo_root: &Path) -> Result<()> {
+        let log_file = Self::log_file_path(repo_root);
+
+        if let Some(parent) = log_file.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(&log_file, content)?;
+
+        Ok(())
+    }
+
+    /// Record `snapshot` (the state as it was before the operation being
+    /// logged) under `description`, tagged with `kind` and, when the
+    /// operation acted on a worker, its branch/base branch/path (pulled from
+    /// `worker`) so `wt undo` can physically reverse it. Prunes the oldest
+    /// entry once the log is over [`OPLOG_MAX_ENTRIES`].
+    pub fn append(
+        repo_root: &Path,
+        kind: OpKind,
+        name: &str,
+        worker: Option<&Worker>,
+        description: &str,
+        snapshot: &OrchestratorState,
+    ) -> Result<()> {
+        let mut log = Self::load(repo_root)?;
+
+        let id = log.next_id;
+        log.next_id += 1;
+
+        log.entries.push(OpLogEntry {
+            id,
+            kind,
+            timestamp: unix_timestamp(),
+            description: description.to_string(),
+            worktree_name: Some(name.to_string()),
+            branch: worker.map(|w| w.branch.clone()),
+            base_branch: worker.map(|w| w.base_branch.clone()),
+            worktree_path: worker.map(|w| w.worktree_path.clone()),
+            snapshot: snapshot.clone(),
+        });
+
+        while log.entries.len() > OPLOG_MAX_ENTRIES {
+            log.entries.remove(0);
+        }
+
+        log.save(repo_root)
+    }
+
+    /// Pop and return the most recent entry, if any, removing it from the
+    /// log on disk.
+    pub fn pop(repo_root: &Path) -> Result<Option<OpLogEntry>> {
+        let mut log = Self::load(repo_root)?;
+        let entry = log.entries.pop();
+
+        if entry.is_some() {
+            log.save(repo_root)?;
+        }
+
+        Ok(entry)
+    }
+
+    /// Pop and return the entry with `id`, along with every entry recorded
+    /// after it (discarding them too, since they were already superseded by
+    /// restoring `id`'s snapshot). `wt undo <id>` uses this to jump back to a
+    /// specific point in history rather than just the last operation.
+    pub fn remove_by_id(repo_root: &Path, id: u64) -> Result<Option<OpLogEntry>> {
+        let mut log = Self::load(repo_root)?;
+
+        let Some(pos) = log.entries.iter().position(|e| e.id == id) else {
+            return Ok(None);
+        };
+
+        let entry = log.entries.remove(pos);
+        log.entries.truncate(pos);
+        log.save(repo_root)?;
+
+        Ok(Some(entry))
+    }
+}
+
+fn unix_timestamp() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Format a unix timestamp as a short relative age, e.g. `"5m ago"`,
+/// `"3h ago"`, `"2d ago"`.
+pub fn format_age(timestamp: i64) -> String {
+    let secs = (unix_timestamp() - timestamp).max(0);
+
+    if secs < 60 {
+        format!("{}s ago", secs)
+    } else if secs < 3600 {
+        format!("{}m ago", secs / 60)
+    } else if secs < 86400 {
+        format!("{}h ago", secs / 3600)
+    } else {
+        format!("{}d ago", secs / 86400)
+    }
 }
 
 #[cfg(test)]
@@ -175,4 +471,39 @@ mod tests {
         assert!(state.get_worker(&id).is_some());
         assert!(state.get_worker_by_name("test-worker").is_some());
     }
+
+    #[test]
+    fn test_format_age() {
+        let now = unix_timestamp();
+        assert_eq!(format_age(now), "0s ago");
+        assert_eq!(format_age(now - 90), "1m ago");
+        assert_eq!(format_age(now - 7200), "2h ago");
+        assert_eq!(format_age(now - 172_800), "2d ago");
+    }
+
+    #[test]
+    fn test_migrate_missing_version_treated_as_zero() {
+        let value = serde_json::json!({
+            "repo_root": "/home/user/project",
+            "workers": {},
+            "tmux_session": "wt-project",
+            "config": RepoConfig::default(),
+        });
+
+        let migrated = OrchestratorState::migrate(value, 0).unwrap();
+        assert_eq!(
+            migrated.get("version").and_then(|v| v.as_u64()),
+            Some(OrchestratorState::CURRENT_VERSION as u64)
+        );
+    }
+
+    #[test]
+    fn test_migrate_current_version_is_noop() {
+        let value = serde_json::json!({ "version": OrchestratorState::CURRENT_VERSION });
+        let migrated = OrchestratorState::migrate(value, OrchestratorState::CURRENT_VERSION).unwrap();
+        assert_eq!(
+            migrated.get("version").and_then(|v| v.as_u64()),
+            Some(OrchestratorState::CURRENT_VERSION as u64)
+        );
+    }
 }
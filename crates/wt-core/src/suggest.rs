@@ -0,0 +1,115 @@
+//! "Did you mean...?" suggestions for not-found names
+//!
+//! Used by [`crate::error::Error`]'s `WorktreeNotFound`, `WorkerNotFound`,
+//! and `BranchNotFound` variants to turn a bare "does not exist" into a
+//! pointer at the closest existing name, the same way a shell's command-not-
+//! found hook does.
+
+/// Damerau-Levenshtein edit distance (insertions, deletions, substitutions,
+/// and adjacent transpositions all cost 1), case-insensitive. This is the
+/// "optimal string alignment" variant, which slightly overcounts some
+/// pathological overlapping-transposition cases but is exact for the short,
+/// mostly-typo inputs worktree/worker/branch names are.
+fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let (la, lb) = (a.len(), b.len());
+
+    let mut d = vec![vec![0usize; lb + 1]; la + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=lb {
+        d[0][j] = j;
+    }
+
+    for i in 1..=la {
+        for j in 1..=lb {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + cost);
+            }
+        }
+    }
+
+    d[la][lb]
+}
+
+/// The closest name in `candidates` to `target`, within an edit-distance
+/// threshold of `target.len() / 3` (minimum 1) so wildly different names
+/// don't get offered as a "did you mean". Ties keep `candidates`' order.
+/// `None` if `candidates` is empty or nothing is close enough.
+pub fn closest(target: &str, candidates: &[String]) -> Option<String> {
+    let threshold = (target.chars().count() / 3).max(1);
+
+    candidates
+        .iter()
+        .map(|c| (c, distance(target, c)))
+        .filter(|(_, d)| *d <= threshold)
+        .min_by_key(|(_, d)| *d)
+        .map(|(c, _)| c.clone())
+}
+
+/// Render the "(did you mean '...'?)" / "(known: ...)" suffix appended to
+/// not-found error messages: the closest match if one is within threshold,
+/// otherwise every candidate so the user can scan for the right one.
+pub fn suffix(target: &str, candidates: &[String]) -> String {
+    if candidates.is_empty() {
+        return String::new();
+    }
+
+    match closest(target, candidates) {
+        Some(closest) => format!(" (did you mean '{}'?)", closest),
+        None => format!(" (known: {})", candidates.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_match_has_zero_distance() {
+        assert_eq!(distance("feature", "feature"), 0);
+    }
+
+    #[test]
+    fn transposition_counts_as_one_edit() {
+        assert_eq!(distance("feature", "faeture"), 1);
+    }
+
+    #[test]
+    fn case_insensitive() {
+        assert_eq!(distance("Feature", "feature"), 0);
+        assert_eq!(closest("FEATURE", &["feature".to_string()]), Some("feature".to_string()));
+    }
+
+    #[test]
+    fn empty_candidates_yield_no_suggestion() {
+        assert_eq!(closest("feature", &[]), None);
+        assert_eq!(suffix("feature", &[]), "");
+    }
+
+    #[test]
+    fn picks_closest_within_threshold() {
+        let candidates = vec!["feature".to_string(), "feat".to_string(), "bugfix".to_string()];
+        assert_eq!(closest("featuer", &candidates), Some("feature".to_string()));
+    }
+
+    #[test]
+    fn nothing_close_lists_all_candidates() {
+        let candidates = vec!["alpha".to_string(), "beta".to_string()];
+        assert_eq!(closest("zzzzzzzz", &candidates), None);
+        assert_eq!(suffix("zzzzzzzz", &candidates), " (known: alpha, beta)");
+    }
+
+    #[test]
+    fn ties_keep_candidate_order() {
+        // "cat" is one substitution away from both "bat" and "cot".
+        let candidates = vec!["bat".to_string(), "cot".to_string()];
+        assert_eq!(closest("cat", &candidates), Some("bat".to_string()));
+    }
+}
@@ -0,0 +1,89 @@
+//! Minimal mustache-style template interpolation
+//!
+//! Used to fill in `templates/task.md`, `templates/spawn-prompt`, and
+//! `templates/status` with per-worker variables. Only `{{ key }}` tokens are
+//! supported (whitespace inside the braces is trimmed); unknown tokens are
+//! left untouched so templates stay forgiving of typos or future keys.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Render a template string, substituting `{{ key }}` tokens from `vars`.
+pub fn render(template: &str, vars: &HashMap<&str, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        output.push_str(&rest[..start]);
+        rest = &rest[start + 2..];
+
+        match rest.find("}}") {
+            Some(end) => {
+                let key = rest[..end].trim();
+                match vars.get(key) {
+                    Some(value) => output.push_str(value),
+                    None => {
+                        output.push_str("{{");
+                        output.push_str(&rest[..end]);
+                        output.push_str("}}");
+                    }
+                }
+                rest = &rest[end + 2..];
+            }
+            None => {
+                // Unmatched "{{" with no closing brace; leave as-is.
+                output.push_str("{{");
+                break;
+            }
+        }
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// Load a user-overridable template from `<repo_root>/templates/<name>`,
+/// falling back to `default` when it hasn't been customized.
+pub fn load_or_default(repo_root: &Path, name: &str, default: &str) -> String {
+    let path = repo_root.join("templates").join(name);
+    std::fs::read_to_string(path).unwrap_or_else(|_| default.to_string())
+}
+
+/// Load a user-overridable `wt init` template from
+/// `<repo_root>/.wt/templates/<name>`, falling back to the embedded
+/// `default`. Kept separate from [`load_or_default`]'s `templates/` lookup
+/// since init templates (CLAUDE.md, issue docs, ...) are project scaffolding
+/// dropped once at init time, not per-worktree/per-spawn templates.
+pub fn load_init_override(repo_root: &Path, name: &str, default: &str) -> String {
+    let path = repo_root.join(".wt").join("templates").join(name);
+    std::fs::read_to_string(path).unwrap_or_else(|_| default.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitutes_known_keys() {
+        let mut vars = HashMap::new();
+        vars.insert("name", "feature-auth".to_string());
+        vars.insert("branch", "feature-auth".to_string());
+
+        let rendered = render("Worker {{ name }} on branch {{branch}}", &vars);
+        assert_eq!(rendered, "Worker feature-auth on branch feature-auth");
+    }
+
+    #[test]
+    fn leaves_unknown_tokens_untouched() {
+        let vars = HashMap::new();
+        let rendered = render("Issue: {{ issue }}", &vars);
+        assert_eq!(rendered, "Issue: {{ issue }}");
+    }
+
+    #[test]
+    fn handles_unmatched_braces() {
+        let vars = HashMap::new();
+        let rendered = render("dangling {{ token", &vars);
+        assert_eq!(rendered, "dangling {{ token");
+    }
+}
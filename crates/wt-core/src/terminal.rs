@@ -0,0 +1,632 @@
+//! Terminal emulator detection and tab/window spawning
+//!
+//! `open_tab` auto-detects the terminal from environment variables and
+//! drives it with a per-emulator recipe: osascript for the macOS apps
+//! (gated behind `cfg(target_os = "macos")`), and a native CLI everywhere
+//! else — gnome-terminal/konsole/foot/xterm on Linux, `wt.exe` on Windows,
+//! and the cross-platform kitty/wezterm CLIs on both. That only covers the
+//! handful of emulators baked into [`Terminal`]; [`crate::config::TerminalConfig`]
+//! lets a user on anything else supply their own command/args template
+//! instead of waiting on a new `Terminal` variant.
+
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::{RemoteConfig, TerminalConfig};
+use crate::error::Result;
+
+/// How a worktree's shell should be placed relative to whatever's already
+/// open. `SplitHorizontal`/`SplitVertical` follow tmux's `-h`/`-v` naming:
+/// horizontal puts the new pane side by side (split by a vertical line),
+/// vertical stacks it above/below (split by a horizontal line).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchMode {
+    Tab,
+    SplitHorizontal,
+    SplitVertical,
+    Window,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Terminal {
+    ITerm2,
+    TerminalApp,
+    Ghostty,
+    Kitty,
+    WezTerm,
+    Alacritty,
+    WindowsTerminal,
+    GnomeTerminal,
+    Konsole,
+    Foot,
+    Xterm,
+    Unknown(String),
+}
+
+impl Terminal {
+    pub fn name(&self) -> &str {
+        match self {
+            Terminal::ITerm2 => "iTerm2",
+            Terminal::TerminalApp => "Terminal.app",
+            Terminal::Ghostty => "Ghostty",
+            Terminal::Kitty => "Kitty",
+            Terminal::WezTerm => "WezTerm",
+            Terminal::Alacritty => "Alacritty",
+            Terminal::WindowsTerminal => "Windows Terminal",
+            Terminal::GnomeTerminal => "GNOME Terminal",
+            Terminal::Konsole => "Konsole",
+            Terminal::Foot => "foot",
+            Terminal::Xterm => "xterm",
+            Terminal::Unknown(name) => name,
+        }
+    }
+
+    pub fn supports_tabs(&self) -> bool {
+        matches!(
+            self,
+            Terminal::ITerm2
+                | Terminal::TerminalApp
+                | Terminal::Ghostty
+                | Terminal::Kitty
+                | Terminal::WezTerm
+                | Terminal::WindowsTerminal
+                | Terminal::GnomeTerminal
+                | Terminal::Konsole
+        )
+    }
+}
+
+/// Detect the current terminal emulator from environment variables.
+pub fn detect_terminal() -> Terminal {
+    // Check TERM_PROGRAM first
+    if let Ok(term) = std::env::var("TERM_PROGRAM") {
+        match term.to_lowercase().as_str() {
+            "iterm.app" => return Terminal::ITerm2,
+            "apple_terminal" => return Terminal::TerminalApp,
+            "ghostty" => return Terminal::Ghostty,
+            "wezterm" => return Terminal::WezTerm,
+            "alacritty" => return Terminal::Alacritty,
+            _ => {}
+        }
+    }
+
+    // Check Kitty
+    if std::env::var("KITTY_WINDOW_ID").is_ok() {
+        return Terminal::Kitty;
+    }
+
+    // Check WezTerm
+    if std::env::var("WEZTERM_UNIX_SOCKET").is_ok() {
+        return Terminal::WezTerm;
+    }
+
+    // Windows Terminal sets WT_SESSION for every pane it hosts.
+    if std::env::var("WT_SESSION").is_ok() {
+        return Terminal::WindowsTerminal;
+    }
+
+    // GNOME Terminal sets this alongside the more generic VTE_VERSION.
+    if std::env::var("GNOME_TERMINAL_SCREEN").is_ok() {
+        return Terminal::GnomeTerminal;
+    }
+
+    if std::env::var("KONSOLE_VERSION").is_ok() {
+        return Terminal::Konsole;
+    }
+
+    if std::env::var("TERM").map(|t| t == "foot" || t == "foot-extra").unwrap_or(false) {
+        return Terminal::Foot;
+    }
+
+    if std::env::var("TERM").map(|t| t.contains("xterm")).unwrap_or(false) {
+        return Terminal::Xterm;
+    }
+
+    let term_program = std::env::var("TERM_PROGRAM").unwrap_or_else(|_| "unknown".to_string());
+    Terminal::Unknown(term_program)
+}
+
+/// Open a new terminal tab/window (or split pane) at `dir`. Prefers
+/// `configured` (a `[terminal]` table from wt.toml) when present,
+/// substituting `{dir}` into its `args`; only auto-detects one of the
+/// built-in [`Terminal`] variants when no override is configured.
+///
+/// When `$TMUX` is set, tmux itself handles the request (`new-window` or
+/// `split-window`) instead of the outer GUI emulator via osascript — the
+/// same precedence editors use, since the outer terminal can't see into the
+/// tmux session the worker actually runs in. A `configured` override has no
+/// notion of this yet, so it's honored as-is regardless of `$TMUX`/`mode`.
+pub fn open_tab(dir: &Path, configured: Option<&TerminalConfig>, mode: LaunchMode) -> Result<bool> {
+    let dir_str = dir.to_string_lossy();
+
+    if let Some(config) = configured {
+        let args: Vec<String> = config
+            .args
+            .iter()
+            .map(|arg| arg.replace("{dir}", &dir_str))
+            .collect();
+        Command::new(&config.command).args(&args).spawn()?;
+        return Ok(true);
+    }
+
+    if std::env::var("TMUX").is_ok() {
+        return open_tmux_hosted(&dir_str, mode);
+    }
+
+    let terminal = detect_terminal();
+
+    match terminal {
+        #[cfg(target_os = "macos")]
+        Terminal::ITerm2 => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: iTerm2 split panes aren't supported yet, opening a tab instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            let create = if mode == LaunchMode::Window {
+                "create window with default profile"
+            } else {
+                "create tab with default profile"
+            };
+            let script = format!(
+                r#"tell application "iTerm2"
+                    tell current window
+                        {}
+                        tell current session
+                            write text "cd '{}'"
+                        end tell
+                    end tell
+                end tell"#,
+                create, dir_str
+            );
+            Command::new("osascript").args(["-e", &script]).output()?;
+            Ok(true)
+        }
+        #[cfg(not(target_os = "macos"))]
+        Terminal::ITerm2 => {
+            eprintln!("Warning: iTerm2 is macOS-only.");
+            eprintln!("  Path: {}", dir_str);
+            Ok(false)
+        }
+        #[cfg(target_os = "macos")]
+        Terminal::TerminalApp => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: Terminal.app split panes aren't supported, opening a new window instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            if mode == LaunchMode::Window {
+                // `do script` with no target window opens a brand new window.
+                let script = format!(r#"tell application "Terminal" to do script "cd '{}'""#, dir_str);
+                Command::new("osascript").args(["-e", &script]).output()?;
+                return Ok(true);
+            }
+            let script = format!(
+                r#"tell application "Terminal"
+                    activate
+                    tell application "System Events" to keystroke "t" using command down
+                    delay 0.3
+                    do script "cd '{}'" in front window
+                end tell"#,
+                dir_str
+            );
+            Command::new("osascript").args(["-e", &script]).output()?;
+            Ok(true)
+        }
+        #[cfg(not(target_os = "macos"))]
+        Terminal::TerminalApp => {
+            eprintln!("Warning: Terminal.app is macOS-only.");
+            eprintln!("  Path: {}", dir_str);
+            Ok(false)
+        }
+        Terminal::Ghostty => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: Ghostty split panes aren't supported, opening a new window instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            #[cfg(target_os = "macos")]
+            {
+                // Ghostty uses a different approach - open new window
+                Command::new("open").args(["-a", "Ghostty", &dir_str]).output()?;
+            }
+            #[cfg(not(target_os = "macos"))]
+            {
+                Command::new("ghostty").args(["--working-directory", &dir_str]).spawn()?;
+            }
+            Ok(true)
+        }
+        Terminal::WindowsTerminal => {
+            if !command_exists("wt.exe") {
+                eprintln!("Warning: wt.exe (Windows Terminal) not found on PATH.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            match mode {
+                LaunchMode::Tab => {
+                    Command::new("wt.exe").args(["new-tab", "-d", &dir_str]).spawn()?;
+                }
+                LaunchMode::Window => {
+                    Command::new("wt.exe").args(["-w", "new", "new-tab", "-d", &dir_str]).spawn()?;
+                }
+                LaunchMode::SplitHorizontal => {
+                    Command::new("wt.exe").args(["split-pane", "-V", "-d", &dir_str]).spawn()?;
+                }
+                LaunchMode::SplitVertical => {
+                    Command::new("wt.exe").args(["split-pane", "-H", "-d", &dir_str]).spawn()?;
+                }
+            }
+            Ok(true)
+        }
+        Terminal::GnomeTerminal => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: gnome-terminal split panes aren't supported, opening a tab instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            let working_dir_flag = format!("--working-directory={}", dir_str);
+            let mut args = vec![working_dir_flag.as_str()];
+            if mode == LaunchMode::Tab {
+                args.insert(0, "--tab");
+            }
+            Command::new("gnome-terminal").args(&args).spawn()?;
+            Ok(true)
+        }
+        Terminal::Konsole => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: konsole split panes aren't supported, opening a tab instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            let mut args = vec!["--workdir", &dir_str];
+            if mode == LaunchMode::Tab {
+                args.insert(0, "--new-tab");
+            }
+            Command::new("konsole").args(&args).spawn()?;
+            Ok(true)
+        }
+        Terminal::Foot => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: foot doesn't support split panes, opening a new window instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            // foot has no tab concept - every launch is a new window.
+            Command::new("foot").args(["-D", &dir_str]).spawn()?;
+            Ok(true)
+        }
+        Terminal::Xterm => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: xterm doesn't support split panes, opening a new window instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            // xterm has no cwd flag - set it on the spawned process instead.
+            Command::new("xterm").current_dir(dir).spawn()?;
+            Ok(true)
+        }
+        Terminal::Kitty => {
+            if !command_exists("kitten") {
+                eprintln!("Warning: kitten not found. Install it for tab support in Kitty.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            let launch_type = match mode {
+                LaunchMode::Window => "os-window",
+                LaunchMode::SplitHorizontal | LaunchMode::SplitVertical => "window",
+                LaunchMode::Tab => "tab",
+            };
+            let mut args = vec!["@", "launch", "--type", launch_type];
+            let location = match mode {
+                LaunchMode::SplitHorizontal => Some("vsplit"),
+                LaunchMode::SplitVertical => Some("hsplit"),
+                _ => None,
+            };
+            let location_arg;
+            if let Some(location) = location {
+                location_arg = format!("--location={}", location);
+                args.push(&location_arg);
+            }
+            args.push("--cwd");
+            args.push(&dir_str);
+            Command::new("kitten").args(&args).output()?;
+            Ok(true)
+        }
+        Terminal::WezTerm => {
+            if !command_exists("wezterm") {
+                eprintln!("Warning: wezterm CLI not found.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            match mode {
+                LaunchMode::Tab => {
+                    Command::new("wezterm").args(["cli", "spawn", "--cwd", &dir_str]).output()?;
+                }
+                LaunchMode::Window => {
+                    Command::new("wezterm")
+                        .args(["cli", "spawn", "--new-window", "--cwd", &dir_str])
+                        .output()?;
+                }
+                LaunchMode::SplitHorizontal => {
+                    Command::new("wezterm")
+                        .args(["cli", "split-pane", "--horizontal", "--cwd", &dir_str])
+                        .output()?;
+                }
+                LaunchMode::SplitVertical => {
+                    Command::new("wezterm")
+                        .args(["cli", "split-pane", "--bottom", "--cwd", &dir_str])
+                        .output()?;
+                }
+            }
+            Ok(true)
+        }
+        Terminal::Alacritty => {
+            if mode != LaunchMode::Tab && mode != LaunchMode::Window {
+                eprintln!("Warning: Alacritty doesn't support split panes, opening a new window instead.");
+                eprintln!("  Path: {}", dir_str);
+                return Ok(false);
+            }
+            // Alacritty doesn't support tabs, open new window
+            Command::new("alacritty").args(["--working-directory", &dir_str]).spawn()?;
+            Ok(true)
+        }
+        Terminal::Unknown(_) => {
+            eprintln!(
+                "Warning: Terminal '{}' not supported for opening tabs. Add a [terminal] table to wt.toml to teach wt its launch command.",
+                terminal.name()
+            );
+            eprintln!("  Path: {}", dir_str);
+            Ok(false)
+        }
+    }
+}
+
+/// The `ssh` argv that reaches a `[remote]`-hosted worktree's tmux session:
+/// `-t` forces a PTY so tmux renders correctly over the link.
+fn ssh_attach_argv(remote: &RemoteConfig, session: &str) -> Vec<String> {
+    vec![
+        "ssh".to_string(),
+        "-t".to_string(),
+        remote.host.clone(),
+        format!("tmux attach -t {}", session),
+    ]
+}
+
+/// Open a terminal tab/window (or split pane) that runs `ssh -t <host> 'tmux
+/// attach -t <session>'` instead of `cd`-ing into a local worktree, for a
+/// worktree whose `wt.toml` has a `[remote]` block (see
+/// [`crate::config::WtToml::is_remote`]). Mirrors [`open_tab`]'s
+/// auto-detection and per-emulator dispatch, substituting the SSH attach
+/// command everywhere `open_tab` would `cd`. `configured` overrides still
+/// apply, same as `open_tab` — they have no notion of remote vs. local.
+pub fn open_remote_tab(
+    remote: &RemoteConfig,
+    session: &str,
+    configured: Option<&TerminalConfig>,
+    mode: LaunchMode,
+) -> Result<bool> {
+    let argv = ssh_attach_argv(remote, session);
+    let command_str = argv.join(" ");
+
+    if let Some(config) = configured {
+        let args: Vec<String> = config
+            .args
+            .iter()
+            .map(|arg| arg.replace("{dir}", &command_str))
+            .collect();
+        Command::new(&config.command).args(&args).spawn()?;
+        return Ok(true);
+    }
+
+    if std::env::var("TMUX").is_ok() {
+        if !command_exists("tmux") {
+            eprintln!("Warning: inside tmux but the `tmux` binary isn't on PATH.");
+            return Ok(false);
+        }
+        return match mode {
+            LaunchMode::Tab | LaunchMode::Window => {
+                Command::new("tmux").args(["new-window"]).args(&argv).output()?;
+                Ok(true)
+            }
+            LaunchMode::SplitHorizontal | LaunchMode::SplitVertical => {
+                let direction = if mode == LaunchMode::SplitHorizontal { "-h" } else { "-v" };
+                Command::new("tmux")
+                    .args(["split-window", direction])
+                    .args(&argv)
+                    .output()?;
+                Ok(true)
+            }
+        };
+    }
+
+    let terminal = detect_terminal();
+
+    match terminal {
+        #[cfg(target_os = "macos")]
+        Terminal::ITerm2 => {
+            let create = if mode == LaunchMode::Window {
+                "create window with default profile"
+            } else {
+                "create tab with default profile"
+            };
+            let script = format!(
+                r#"tell application "iTerm2"
+                    tell current window
+                        {}
+                        tell current session
+                            write text "{}"
+                        end tell
+                    end tell
+                end tell"#,
+                create, command_str
+            );
+            Command::new("osascript").args(["-e", &script]).output()?;
+            Ok(true)
+        }
+        #[cfg(target_os = "macos")]
+        Terminal::TerminalApp => {
+            let script = format!(r#"tell application "Terminal" to do script "{}""#, command_str);
+            Command::new("osascript").args(["-e", &script]).output()?;
+            Ok(true)
+        }
+        Terminal::GnomeTerminal => {
+            let mut args: Vec<&str> = if mode == LaunchMode::Tab { vec!["--tab"] } else { vec![] };
+            args.push("--");
+            let command_refs: Vec<&str> = argv.iter().map(String::as_str).collect();
+            args.extend(command_refs);
+            Command::new("gnome-terminal").args(&args).spawn()?;
+            Ok(true)
+        }
+        Terminal::Konsole => {
+            let mut args: Vec<&str> = if mode == LaunchMode::Tab { vec!["--new-tab"] } else { vec![] };
+            args.push("-e");
+            let command_refs: Vec<&str> = argv.iter().map(String::as_str).collect();
+            args.extend(command_refs);
+            Command::new("konsole").args(&args).spawn()?;
+            Ok(true)
+        }
+        Terminal::Foot => {
+            Command::new("foot").args(&argv).spawn()?;
+            Ok(true)
+        }
+        Terminal::Xterm => {
+            Command::new("xterm").args(["-e", &command_str]).spawn()?;
+            Ok(true)
+        }
+        Terminal::Alacritty => {
+            Command::new("alacritty").args(["-e"]).args(&argv).spawn()?;
+            Ok(true)
+        }
+        Terminal::WindowsTerminal => {
+            if !command_exists("wt.exe") {
+                eprintln!("Warning: wt.exe (Windows Terminal) not found on PATH.");
+                return Ok(false);
+            }
+            let subcommand = match mode {
+                LaunchMode::Tab => vec!["new-tab"],
+                LaunchMode::Window => vec!["-w", "new", "new-tab"],
+                LaunchMode::SplitHorizontal => vec!["split-pane", "-V"],
+                LaunchMode::SplitVertical => vec!["split-pane", "-H"],
+            };
+            Command::new("wt.exe").args(&subcommand).args(&argv).spawn()?;
+            Ok(true)
+        }
+        Terminal::Kitty => {
+            if !command_exists("kitten") {
+                eprintln!("Warning: kitten not found. Install it for tab support in Kitty.");
+                return Ok(false);
+            }
+            let launch_type = match mode {
+                LaunchMode::Window => "os-window",
+                LaunchMode::SplitHorizontal | LaunchMode::SplitVertical => "window",
+                LaunchMode::Tab => "tab",
+            };
+            let mut args = vec!["@".to_string(), "launch".to_string(), "--type".to_string(), launch_type.to_string()];
+            match mode {
+                LaunchMode::SplitHorizontal => args.push("--location=vsplit".to_string()),
+                LaunchMode::SplitVertical => args.push("--location=hsplit".to_string()),
+                _ => {}
+            }
+            args.extend(argv.clone());
+            Command::new("kitten").args(&args).output()?;
+            Ok(true)
+        }
+        Terminal::WezTerm => {
+            if !command_exists("wezterm") {
+                eprintln!("Warning: wezterm CLI not found.");
+                return Ok(false);
+            }
+            let mut args: Vec<String> = match mode {
+                LaunchMode::Tab => vec!["cli".to_string(), "spawn".to_string()],
+                LaunchMode::Window => vec!["cli".to_string(), "spawn".to_string(), "--new-window".to_string()],
+                LaunchMode::SplitHorizontal => {
+                    vec!["cli".to_string(), "split-pane".to_string(), "--horizontal".to_string()]
+                }
+                LaunchMode::SplitVertical => vec!["cli".to_string(), "split-pane".to_string(), "--bottom".to_string()],
+            };
+            args.push("--".to_string());
+            args.extend(argv.clone());
+            Command::new("wezterm").args(&args).output()?;
+            Ok(true)
+        }
+        #[cfg(not(target_os = "macos"))]
+        Terminal::Ghostty => {
+            Command::new("ghostty").args(["-e", &command_str]).spawn()?;
+            Ok(true)
+        }
+        other => {
+            eprintln!(
+                "Warning: remote launch not supported for terminal '{}'. Run `{}` manually.",
+                other.name(),
+                command_str
+            );
+            Ok(false)
+        }
+    }
+}
+
+/// Route a launch request through tmux itself (`new-window`/`split-window`)
+/// rather than the outer GUI emulator, for when `$TMUX` shows we're already
+/// hosted inside a multiplexer. Window and tab requests both land as a new
+/// tmux window, since tmux has no separate OS-window concept.
+fn open_tmux_hosted(dir_str: &str, mode: LaunchMode) -> Result<bool> {
+    if !command_exists("tmux") {
+        eprintln!("Warning: inside tmux but the `tmux` binary isn't on PATH.");
+        eprintln!("  Path: {}", dir_str);
+        return Ok(false);
+    }
+    match mode {
+        LaunchMode::Tab | LaunchMode::Window => {
+            Command::new("tmux").args(["new-window", "-c", dir_str]).output()?;
+        }
+        LaunchMode::SplitHorizontal | LaunchMode::SplitVertical => {
+            let direction = if mode == LaunchMode::SplitHorizontal { "-h" } else { "-v" };
+            Command::new("tmux")
+                .args(["split-window", direction, "-c", dir_str])
+                .output()?;
+        }
+    }
+    Ok(true)
+}
+
+/// Check if a command is available on `PATH`.
+pub fn command_exists(cmd: &str) -> bool {
+    which::which(cmd).is_ok()
+}
+
+/// Whether a dependency `wt` shells out to is present, and whether its
+/// absence should be treated as fatal by a scripted consumer of `wt health
+/// --json` (only `git` is; everything else degrades a feature rather than
+/// breaking `wt` outright).
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DependencyStatus {
+    pub name: String,
+    pub available: bool,
+    pub required: bool,
+}
+
+/// Check every dependency `wt` knows how to shell out to.
+pub fn check_dependencies() -> Vec<DependencyStatus> {
+    vec![
+        DependencyStatus {
+            name: "git".to_string(),
+            available: command_exists("git"),
+            required: true,
+        },
+        DependencyStatus {
+            name: "tmux".to_string(),
+            available: command_exists("tmux"),
+            required: false,
+        },
+        DependencyStatus {
+            name: "jq".to_string(),
+            available: command_exists("jq"),
+            required: false,
+        },
+        DependencyStatus {
+            name: "gh".to_string(),
+            available: command_exists("gh"),
+            required: false,
+        },
+    ]
+}
@@ -0,0 +1,333 @@
+//! Pluggable version-control backends
+//!
+//! `wt` was written assuming plain git, but the worktree/spawn/review/merge
+//! flow only needs a handful of operations. `VcsBackend` captures those so
+//! other tools (currently `jj`, with its colocated workspaces) can drive the
+//! same flow. Backend selection happens once, in [`detect_backend`].
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::error::{Error, Result};
+use crate::git;
+
+/// Operations `wt` needs from a version-control tool.
+///
+/// Kept small and object-safe so third parties can register additional
+/// backends (e.g. Mercurial) without touching the rest of `wt`.
+pub trait VcsBackend {
+    /// Create a new worktree/workspace directory named `name`, on `branch`
+    /// (a bookmark, for `JjBackend`), branching from `base`. `branch` and
+    /// `name` are independent — `wt create foo bar` creates a worktree
+    /// directory `foo` on branch `bar` — so callers shouldn't assume they
+    /// match even though most do.
+    fn create_worktree(&self, name: &str, branch: &str, base: &str) -> Result<()>;
+
+    /// List the names of existing worktrees/workspaces.
+    fn list_worktrees(&self) -> Result<Vec<String>>;
+
+    /// Name of the branch/bookmark a worktree/workspace is on, for `wt ps`
+    /// and `wt status`'s BRANCH column.
+    fn worktree_branch(&self, name: &str) -> Result<String>;
+
+    /// One-line summaries of commits in `name` not yet in `base`, for
+    /// `wt ps`'s COMMITS column.
+    fn commits_ahead(&self, name: &str, base: &str) -> Result<Vec<String>>;
+
+    /// Diff a worktree against its base. `full` requests the full patch
+    /// instead of a summary.
+    fn diff(&self, name: &str, full: bool) -> Result<String>;
+
+    /// Merge a worktree's changes into the current branch.
+    fn merge(&self, name: &str) -> Result<()>;
+
+    /// Whether a worktree has uncommitted changes.
+    fn is_dirty(&self, name: &str) -> Result<bool>;
+
+    /// Remove a worktree/workspace, optionally forcing past uncommitted
+    /// changes.
+    fn remove(&self, name: &str, force: bool) -> Result<()>;
+}
+
+/// Git-backed implementation, built on the existing [`crate::git`] module.
+pub struct GitBackend {
+    worktrees_dir: PathBuf,
+    repo_root: PathBuf,
+}
+
+impl GitBackend {
+    pub fn new(repo_root: PathBuf, worktrees_dir: PathBuf) -> Self {
+        Self {
+            repo_root,
+            worktrees_dir,
+        }
+    }
+}
+
+impl VcsBackend for GitBackend {
+    fn create_worktree(&self, name: &str, branch: &str, base: &str) -> Result<()> {
+        let path = self.worktrees_dir.join(name);
+        git::create_worktree(&path, branch, base)
+    }
+
+    fn list_worktrees(&self) -> Result<Vec<String>> {
+        git::list_worktree_names(&self.worktrees_dir)
+    }
+
+    fn worktree_branch(&self, name: &str) -> Result<String> {
+        git::get_worktree_branch(&self.worktrees_dir.join(name))
+    }
+
+    fn commits_ahead(&self, name: &str, base: &str) -> Result<Vec<String>> {
+        git::get_commits_ahead(&self.worktrees_dir.join(name), base)
+    }
+
+    fn diff(&self, name: &str, full: bool) -> Result<String> {
+        let path = self.worktrees_dir.join(name);
+        let base = git::get_current_branch().unwrap_or_else(|_| "HEAD".to_string());
+        if full {
+            git::get_diff(&path, &base)
+        } else {
+            git::get_diff_stat(&path, &base)
+        }
+    }
+
+    fn merge(&self, name: &str) -> Result<()> {
+        let path = self.worktrees_dir.join(name);
+        let branch = git::get_worktree_branch(&path)?;
+        git::merge_branch(&branch)
+    }
+
+    fn is_dirty(&self, name: &str) -> Result<bool> {
+        git::has_uncommitted_changes(&self.worktrees_dir.join(name))
+    }
+
+    fn remove(&self, name: &str, force: bool) -> Result<()> {
+        let _ = &self.repo_root;
+        git::remove_worktree(&self.worktrees_dir.join(name), force)
+    }
+}
+
+/// Jujutsu-backed implementation, shelling out to `jj workspace *`.
+pub struct JjBackend {
+    repo_root: PathBuf,
+    worktrees_dir: PathBuf,
+}
+
+impl JjBackend {
+    pub fn new(repo_root: PathBuf, worktrees_dir: PathBuf) -> Self {
+        Self {
+            repo_root,
+            worktrees_dir,
+        }
+    }
+
+    /// Run a `jj` subcommand in the repo root, returning the `Command`
+    /// alongside its `Output` so a failed exit can be turned into an
+    /// [`Error::Process`] with the exact argv still in hand. A spawn/wait
+    /// failure (`jj` not on `PATH`, etc.) surfaces as [`Error::Io`] via `?`,
+    /// keeping the original `std::io::Error` instead of stringifying it.
+    fn jj(&self, args: &[&str]) -> Result<(Command, std::process::Output)> {
+        self.jj_in(&self.repo_root, args)
+    }
+
+    /// Like [`Self::jj`], but runs in an arbitrary directory (a worktree's
+    /// path rather than the repo root).
+    fn jj_in(&self, dir: &Path, args: &[&str]) -> Result<(Command, std::process::Output)> {
+        let mut cmd = Command::new("jj");
+        cmd.args(args).current_dir(dir);
+        let output = cmd.output()?;
+        Ok((cmd, output))
+    }
+}
+
+/// Commit message for the merge commit [`JjBackend::merge`] creates.
+fn merge_commit_message(branch: &str) -> String {
+    format!("Merge {branch} into the current change")
+}
+
+/// Build an [`Error::Process`] from a `jj` invocation that exited non-zero.
+fn process_error(cmd: &Command, output: &std::process::Output) -> Error {
+    Error::Process {
+        program: cmd.get_program().to_string_lossy().to_string(),
+        args: cmd.get_args().map(|a| a.to_string_lossy().to_string()).collect(),
+        status: output.status.code(),
+        stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+    }
+}
+
+impl VcsBackend for JjBackend {
+    fn create_worktree(&self, name: &str, branch: &str, base: &str) -> Result<()> {
+        let path = self.worktrees_dir.join(name);
+        let (cmd, output) = self.jj(&[
+            "workspace",
+            "add",
+            "--revision",
+            base,
+            &path.to_string_lossy(),
+        ])?;
+
+        if !output.status.success() {
+            return Err(process_error(&cmd, &output));
+        }
+
+        // `workspace add` leaves the new working-copy commit bookmark-less;
+        // give it `branch` as a bookmark when the caller asked for one
+        // distinct from the workspace's own name. Best-effort, like
+        // upstream tracking in the git backend — a naming collision here
+        // shouldn't fail worktree creation.
+        if branch != name {
+            let _ = Command::new("jj")
+                .args(["bookmark", "create", branch, "-r", "@"])
+                .current_dir(&path)
+                .output();
+        }
+
+        Ok(())
+    }
+
+    fn list_worktrees(&self) -> Result<Vec<String>> {
+        let (cmd, output) = self.jj(&["workspace", "list"])?;
+        if !output.status.success() {
+            return Err(process_error(&cmd, &output));
+        }
+
+        let text = String::from_utf8_lossy(&output.stdout);
+        Ok(text
+            .lines()
+            .filter_map(|line| line.split(':').next())
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .collect())
+    }
+
+    fn worktree_branch(&self, name: &str) -> Result<String> {
+        let path = self.worktrees_dir.join(name);
+        let (cmd, output) =
+            self.jj_in(&path, &["log", "-r", "@", "--no-graph", "-T", "bookmarks"])?;
+
+        if !output.status.success() {
+            return Err(process_error(&cmd, &output));
+        }
+
+        let bookmarks = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        // A workspace's working-copy commit has no bookmark until one is
+        // explicitly set; fall back to the workspace name itself.
+        Ok(if bookmarks.is_empty() { name.to_string() } else { bookmarks })
+    }
+
+    fn commits_ahead(&self, name: &str, base: &str) -> Result<Vec<String>> {
+        let path = self.worktrees_dir.join(name);
+        let (_, output) = self.jj_in(
+            &path,
+            &[
+                "log",
+                "-r",
+                &format!("{}..@", base),
+                "--no-graph",
+                "-T",
+                "description.first_line() ++ \"\\n\"",
+            ],
+        )?;
+
+        if !output.status.success() {
+            return Ok(Vec::new());
+        }
+
+        let text = String::from_utf8_lossy(&output.stdout);
+        Ok(text
+            .lines()
+            .map(|s| s.to_string())
+            .filter(|s| !s.is_empty())
+            .collect())
+    }
+
+    fn diff(&self, name: &str, full: bool) -> Result<String> {
+        let path = self.worktrees_dir.join(name);
+        let mut args = vec!["diff"];
+        if !full {
+            args.push("--stat");
+        }
+
+        let (cmd, output) = self.jj_in(&path, &args)?;
+
+        if !output.status.success() {
+            return Err(process_error(&cmd, &output));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+
+    fn merge(&self, name: &str) -> Result<()> {
+        // `jj new @ <branch>` creates a new change with both the repo root's
+        // current working-copy commit and the worktree's bookmark as
+        // parents, i.e. an actual merge commit — unlike `workspace
+        // update-stale`, which only re-syncs a workspace's own view after
+        // it's gone stale and doesn't integrate anything. The working copy
+        // has no staging area, so `@` already reflects any uncommitted
+        // changes in the repo root; jj snapshots it automatically.
+        let branch = self.worktree_branch(name)?;
+        let message = merge_commit_message(&branch);
+        let (cmd, output) = self.jj(&["new", "@", &branch, "-m", &message])?;
+        if !output.status.success() {
+            return Err(process_error(&cmd, &output));
+        }
+        Ok(())
+    }
+
+    fn is_dirty(&self, name: &str) -> Result<bool> {
+        let path = self.worktrees_dir.join(name);
+        let (_, output) = self.jj_in(&path, &["diff", "--summary"])?;
+
+        Ok(!output.stdout.is_empty())
+    }
+
+    fn remove(&self, name: &str, _force: bool) -> Result<()> {
+        let (cmd, output) = self.jj(&["workspace", "forget", name])?;
+        if !output.status.success() {
+            return Err(process_error(&cmd, &output));
+        }
+        Ok(())
+    }
+}
+
+/// Auto-detect which backend to use for a repository.
+///
+/// Prefers an explicit `vcs` key in `wt.toml`, falling back to the presence
+/// of `.jj/` vs `.git/` in the repo root.
+pub fn detect_backend(repo_root: &Path, worktrees_dir: &Path) -> Box<dyn VcsBackend> {
+    let configured = crate::config::WtToml::load(repo_root)
+        .ok()
+        .flatten()
+        .and_then(|toml| toml.vcs);
+
+    let kind = configured.unwrap_or_else(|| {
+        if repo_root.join(".jj").exists() {
+            "jj".to_string()
+        } else {
+            "git".to_string()
+        }
+    });
+
+    match kind.as_str() {
+        "jj" => Box::new(JjBackend::new(repo_root.to_path_buf(), worktrees_dir.to_path_buf())),
+        _ => Box::new(GitBackend::new(repo_root.to_path_buf(), worktrees_dir.to_path_buf())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `JjBackend::merge` itself shells out to a real `jj` repo, which this
+    // tree has no fixture/tempdir infrastructure for (no other backend in
+    // this file is exercised by tests either); this covers the one piece of
+    // it that's pure logic.
+    #[test]
+    fn merge_commit_message_names_the_branch() {
+        assert_eq!(
+            merge_commit_message("feature-x"),
+            "Merge feature-x into the current change"
+        );
+    }
+}
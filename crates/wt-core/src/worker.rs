@@ -53,6 +53,15 @@ pub struct Worker {
     pub created_at: DateTime<Utc>,
     /// When the worker was last updated
     pub updated_at: DateTime<Utc>,
+    /// PIDs of background services launched alongside this worker (e.g.
+    /// `npm run dev`), torn down on remove/exit/kill.
+    #[serde(default)]
+    pub service_pids: Vec<u32>,
+    /// User-defined labels (e.g. `"feature-x"`) for grouping related workers
+    /// for bulk `--tag` operations, since name globbing alone can't express
+    /// an arbitrary grouping.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl Worker {
@@ -77,6 +86,8 @@ impl Worker {
             tmux_window: Some(name),
             created_at: now,
             updated_at: now,
+            service_pids: Vec::new(),
+            tags: Vec::new(),
         }
     }
 
@@ -92,6 +103,46 @@ impl Worker {
         self.updated_at = Utc::now();
     }
 
+    /// Add `tag` if not already present. Returns `false` if it was already set.
+    pub fn add_tag(&mut self, tag: &str) -> bool {
+        if self.tags.iter().any(|t| t == tag) {
+            return false;
+        }
+        self.tags.push(tag.to_string());
+        self.updated_at = Utc::now();
+        true
+    }
+
+    /// Remove `tag` if present. Returns `false` if it wasn't set.
+    pub fn remove_tag(&mut self, tag: &str) -> bool {
+        let before = self.tags.len();
+        self.tags.retain(|t| t != tag);
+        let removed = self.tags.len() != before;
+        if removed {
+            self.updated_at = Utc::now();
+        }
+        removed
+    }
+
+    /// How long it's been since this worker's `updated_at` last moved, as
+    /// of `now`.
+    pub fn staleness(&self, now: DateTime<Utc>) -> chrono::Duration {
+        now - self.updated_at
+    }
+
+    /// Whether this worker has gone longer than `threshold` without an
+    /// update. Used to flag a [`WorkerStatus::Running`] worker as possibly
+    /// stuck rather than just quiet — see [`crate::state::OrchestratorState::stale_workers`].
+    pub fn is_stale(&self, threshold: chrono::Duration) -> bool {
+        self.staleness(Utc::now()) > threshold
+    }
+
+    /// Bump `updated_at` without changing `status`, as a heartbeat for a
+    /// long-running agent to signal it's still alive.
+    pub fn touch(&mut self) {
+        self.updated_at = Utc::now();
+    }
+
     /// Check if the worker is in a terminal state
     pub fn is_terminal(&self) -> bool {
         matches!(
@@ -207,4 +258,33 @@ pub struct FileDiff {
     pub path: String,
     pub insertions: usize,
     pub deletions: usize,
+    /// Set when this file's diff couldn't be computed normally — binary
+    /// content, a merge conflict, or an unreadable file — in which case
+    /// `insertions`/`deletions` are left at `0` rather than silently
+    /// standing in for a real count.
+    #[serde(default)]
+    pub error: Option<FileDiffError>,
+}
+
+/// Why an individual file's diff couldn't be computed normally.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FileDiffError {
+    /// File content is binary; git doesn't report a line-level diff for it.
+    Binary,
+    /// File has unresolved merge conflict markers.
+    Conflict,
+    /// File couldn't be read (e.g. permission denied).
+    AccessDenied,
+}
+
+impl FileDiffError {
+    /// Short human-readable reason, shown next to the file's "!" marker.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            FileDiffError::Binary => "binary",
+            FileDiffError::Conflict => "conflict",
+            FileDiffError::AccessDenied => "access denied",
+        }
+    }
 }
@@ -4,9 +4,10 @@
 
 use std::path::{Path, PathBuf};
 
-use crate::config::run_on_create_hook;
+use crate::config::{run_on_create_hook, ScaffoldFile};
 use crate::error::{Error, Result};
 use crate::git;
+use crate::vcs;
 
 /// Represents a git worktree
 #[derive(Debug, Clone)]
@@ -19,6 +20,36 @@ pub struct Worktree {
     pub branch: String,
 }
 
+/// Why [`Worktree::check_removable`] refused a removal.
+#[derive(Debug, Clone)]
+pub enum WorktreeRemoveFailure {
+    /// The worktree has dirty/untracked files, per [`Worktree::status`]'s summary.
+    UncommittedChanges(String),
+    /// The branch has commits not reachable from the base branch that would
+    /// be lost if the worktree were removed.
+    Unmerged(usize),
+    /// Something else went wrong while checking.
+    Error(String),
+}
+
+impl std::fmt::Display for WorktreeRemoveFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorktreeRemoveFailure::UncommittedChanges(summary) => {
+                write!(f, "worktree has uncommitted changes ({})", summary)
+            }
+            WorktreeRemoveFailure::Unmerged(commits) => {
+                write!(
+                    f,
+                    "branch isn't merged — {} commit(s) would be lost",
+                    commits
+                )
+            }
+            WorktreeRemoveFailure::Error(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
 impl Worktree {
     /// Create a new worktree
     pub fn create(
@@ -28,6 +59,7 @@ impl Worktree {
         branch: Option<&str>,
         base_branch: &str,
         on_create_hook: Option<&str>,
+        scaffold: &[ScaffoldFile],
     ) -> Result<Self> {
         let worktree_path = worktrees_dir.join(name);
 
@@ -47,8 +79,24 @@ impl Worktree {
         // Determine branch name
         let branch = branch.unwrap_or(name);
 
-        // Create the worktree
-        git::create_worktree(&worktree_path, branch, base_branch)?;
+        // Create the worktree/workspace through the configured backend
+        // (git or jj — see `crate::vcs`), so this runs unchanged on either.
+        let repo_root = git_common_dir.parent().unwrap_or(git_common_dir);
+        let backend = vcs::detect_backend(repo_root, worktrees_dir);
+        backend.create_worktree(name, branch, base_branch)?;
+
+        // Materialize any configured scaffold files before the on-create
+        // hook runs, so the hook can see (and further touch) them.
+        if !scaffold.is_empty() {
+            crate::config::materialize_scaffold(
+                repo_root,
+                &worktree_path,
+                name,
+                branch,
+                base_branch,
+                scaffold,
+            )?;
+        }
 
         // Run on-create hook if configured
         if let Some(hook) = on_create_hook {
@@ -81,7 +129,10 @@ impl Worktree {
         let path = worktrees_dir.join(name);
 
         if !path.exists() {
-            return Err(Error::WorktreeNotFound(name.to_string()));
+            return Err(Error::WorktreeNotFound {
+                name: name.to_string(),
+                candidates: git::list_worktree_names(worktrees_dir).unwrap_or_default(),
+            });
         }
 
         let branch = git::get_worktree_branch(&path)?;
@@ -93,6 +144,38 @@ impl Worktree {
         })
     }
 
+    /// Check whether this worktree is safe to remove: no uncommitted
+    /// changes, and no commits on `branch` that aren't reachable from
+    /// `base_branch` (which would otherwise be silently lost).
+    pub fn check_removable(
+        &self,
+        base_branch: &str,
+    ) -> std::result::Result<(), WorktreeRemoveFailure> {
+        let entries = self
+            .status()
+            .map_err(|e| WorktreeRemoveFailure::Error(e.to_string()))?;
+        let summary = crate::git::summarize_status(&entries);
+        if summary != "clean" {
+            return Err(WorktreeRemoveFailure::UncommittedChanges(summary));
+        }
+
+        let commits_ahead = self
+            .get_commits_ahead(base_branch)
+            .map_err(|e| WorktreeRemoveFailure::Error(e.to_string()))?;
+        if !commits_ahead.is_empty() {
+            return Err(WorktreeRemoveFailure::Unmerged(commits_ahead.len()));
+        }
+
+        Ok(())
+    }
+
+    /// Save this worktree's dirty state to the stash, tagged so
+    /// `wt unstash` can find it again. Returns `Ok(false)` if the worktree
+    /// was already clean.
+    pub fn stash(&self) -> Result<bool> {
+        crate::gitbackend::stash_save(&self.path, &self.name, &self.branch)
+    }
+
     /// Remove this worktree
     pub fn remove(&self, force: bool) -> Result<()> {
         // Check for uncommitted changes unless force
@@ -135,6 +218,11 @@ impl Worktree {
         git::has_uncommitted_changes(&self.path)
     }
 
+    /// Get structured, per-file status for this worktree.
+    pub fn status(&self) -> Result<Vec<git::StatusEntry>> {
+        git::get_status(&self.path)
+    }
+
     /// Get commits ahead of base branch
     pub fn get_commits_ahead(&self, base_branch: &str) -> Result<Vec<String>> {
         git::get_commits_ahead(&self.path, base_branch)
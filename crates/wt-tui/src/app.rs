@@ -1,31 +1,242 @@
-//! TUI Application state and logic
+//! TUI application state and logic
 //!
-//! This will be the main application struct that manages:
-//! - Worker list view
-//! - Detail view for selected worker
-//! - Key bindings and navigation
+//! Owns the worker list (refreshed from `spawn::list_tasks`, the same data
+//! `wt ps` renders), the selected worker's diff/task-note preview, and the
+//! quick actions (attach/kill/merge/remove) the dashboard exposes.
 
-use wt_core::OrchestratorState;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use wt_core::spawn::{self, TaskInfo};
+use wt_core::terminal::{self, LaunchMode};
+use wt_core::{config, git, gitbackend};
+
+/// How often [`App::maybe_refresh`] re-polls worktree/spawn state, so
+/// statuses keep moving while an agent runs without redrawing on every tick.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A destructive action the user must confirm before it runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingConfirm {
+    Kill,
+    Remove,
+}
 
-/// TUI application state
-#[allow(dead_code)]
 pub struct App {
-    /// Current state from disk
-    state: Option<OrchestratorState>,
-    /// Currently selected worker index
-    selected: usize,
-    /// Whether the app should quit
-    should_quit: bool,
+    pub tasks: Vec<TaskInfo>,
+    pub selected: usize,
+    pub should_quit: bool,
+    pub diff: String,
+    pub task_notes: String,
+    pub base_branch: String,
+    pub status_message: Option<String>,
+    pub pending_confirm: Option<PendingConfirm>,
+    last_refresh: Instant,
 }
 
 impl App {
-    /// Create a new app instance
-    #[allow(dead_code)]
     pub fn new() -> Self {
-        Self {
-            state: None,
+        let mut app = Self {
+            tasks: Vec::new(),
             selected: 0,
             should_quit: false,
+            diff: String::new(),
+            task_notes: String::new(),
+            base_branch: String::new(),
+            status_message: None,
+            pending_confirm: None,
+            last_refresh: Instant::now() - REFRESH_INTERVAL,
+        };
+        app.refresh();
+        app
+    }
+
+    /// Re-poll worktree/spawn state if [`REFRESH_INTERVAL`] has elapsed.
+    pub fn maybe_refresh(&mut self) {
+        if self.last_refresh.elapsed() >= REFRESH_INTERVAL {
+            self.refresh();
         }
     }
+
+    pub fn refresh(&mut self) {
+        self.tasks = spawn::list_tasks().unwrap_or_default();
+        if self.selected >= self.tasks.len() {
+            self.selected = self.tasks.len().saturating_sub(1);
+        }
+        self.load_selected_details();
+        self.last_refresh = Instant::now();
+    }
+
+    pub fn selected_task(&self) -> Option<&TaskInfo> {
+        self.tasks.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.tasks.is_empty() {
+            return;
+        }
+        self.selected = (self.selected + 1) % self.tasks.len();
+        self.load_selected_details();
+    }
+
+    pub fn select_prev(&mut self) {
+        if self.tasks.is_empty() {
+            return;
+        }
+        self.selected = (self.selected + self.tasks.len() - 1) % self.tasks.len();
+        self.load_selected_details();
+    }
+
+    fn worktree_path(&self, name: &str) -> Option<PathBuf> {
+        git::get_worktrees_dir().ok().map(|dir| dir.join(name))
+    }
+
+    /// Refresh the right pane (diff against base + `.wt/task.md`) for
+    /// whichever worker is now selected.
+    fn load_selected_details(&mut self) {
+        self.diff.clear();
+        self.task_notes.clear();
+        self.base_branch.clear();
+
+        let Some(name) = self.selected_task().map(|t| t.name.clone()) else {
+            return;
+        };
+        let Some(path) = self.worktree_path(&name) else {
+            return;
+        };
+
+        let base = wt_core::config::get_base_branch().unwrap_or_else(|_| "main".to_string());
+        self.diff = git::get_diff(&path, &base).unwrap_or_else(|e| format!("(no diff: {})", e));
+        self.base_branch = base;
+
+        if let Ok(contents) = std::fs::read_to_string(path.join(".wt").join("task.md")) {
+            self.task_notes = contents;
+        }
+    }
+
+    /// Open the selected worker's worktree in a new terminal tab, the same
+    /// [`terminal::open_tab`] launch `wt` would use if it had a dedicated
+    /// `open` command — honors a configured `[terminal]` template before
+    /// falling back to auto-detection.
+    pub fn open_selected(&mut self) {
+        let Some(name) = self.selected_task().map(|t| t.name.clone()) else {
+            return;
+        };
+        let Some(path) = self.worktree_path(&name) else {
+            return;
+        };
+
+        let configured = config::read_layered_wt_toml(Some(&path))
+            .ok()
+            .and_then(|toml| toml.terminal);
+
+        self.status_message = Some(
+            match terminal::open_tab(&path, configured.as_ref(), LaunchMode::Tab) {
+                Ok(true) => format!("Opened '{}' in a new tab", name),
+                Ok(false) => "No supported terminal detected".to_string(),
+                Err(e) => format!("Open failed: {}", e),
+            },
+        );
+    }
+
+    /// Attach to the selected worker's tmux window. On Unix this replaces
+    /// the current process (see `session::attach`), so the TUI never
+    /// regains control — the caller should leave the alternate screen
+    /// first, same as `wt attach` leaves the plain terminal.
+    pub fn attach_selected(&self) -> wt_core::Result<()> {
+        match self.selected_task() {
+            Some(task) => spawn::attach(Some(&task.name), false, false),
+            None => Ok(()),
+        }
+    }
+
+    pub fn kill_selected(&mut self) {
+        let Some(name) = self.selected_task().map(|t| t.name.clone()) else {
+            return;
+        };
+        self.status_message = Some(match spawn::kill(&name) {
+            Ok(()) => format!("Killed '{}'", name),
+            Err(e) => format!("Kill failed: {}", e),
+        });
+        self.refresh();
+    }
+
+    /// Merge the selected worker's branch into the base branch, the same
+    /// fast-forward/three-way analysis `wt merge` uses. Unlike `wt merge`
+    /// this never offers to remove the worktree afterward — use `r`/`R`
+    /// for that once the merge result looks right.
+    pub fn merge_selected(&mut self) {
+        let Some((name, path)) = self
+            .selected_task()
+            .map(|t| t.name.clone())
+            .and_then(|name| self.worktree_path(&name).map(|path| (name, path)))
+        else {
+            return;
+        };
+
+        match git::has_uncommitted_changes(&path) {
+            Ok(true) => {
+                self.status_message =
+                    Some(format!("'{}' has uncommitted changes; commit first", name));
+                return;
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Merge failed: {}", e));
+                return;
+            }
+            Ok(false) => {}
+        }
+
+        let branch = git::get_worktree_branch(&path).unwrap_or_default();
+        let result = git::get_base_repo().and_then(|base_repo| {
+            gitbackend::merge_branch_analyzed(&base_repo, &branch, false, false)
+        });
+
+        self.status_message = Some(match result {
+            Ok(Ok(outcome)) => format!("Merged '{}': {:?}", branch, outcome),
+            Ok(Err(failure)) => format!("Merge of '{}' failed: {:?}", branch, failure),
+            Err(e) => format!("Merge failed: {}", e),
+        });
+        self.refresh();
+    }
+
+    /// Remove the selected worktree. Without `force` this refuses on
+    /// uncommitted changes, same guard `wt remove` uses, minus its
+    /// stash/hook/branch-protection extras — reach for `wt remove` directly
+    /// when those matter.
+    pub fn remove_selected(&mut self, force: bool) {
+        let Some((name, path)) = self
+            .selected_task()
+            .map(|t| t.name.clone())
+            .and_then(|name| self.worktree_path(&name).map(|path| (name, path)))
+        else {
+            return;
+        };
+
+        if !force {
+            match git::has_uncommitted_changes(&path) {
+                Ok(true) => {
+                    self.status_message = Some(format!(
+                        "'{}' has uncommitted changes — confirm again to force-remove",
+                        name
+                    ));
+                    return;
+                }
+                Err(e) => {
+                    self.status_message = Some(format!("Remove failed: {}", e));
+                    return;
+                }
+                Ok(false) => {}
+            }
+        }
+
+        self.status_message = Some(match git::remove_worktree(&path, force) {
+            Ok(()) => {
+                let _ = spawn::unregister(&name);
+                format!("Removed '{}'", name)
+            }
+            Err(e) => format!("Remove failed: {}", e),
+        });
+        self.refresh();
+    }
 }
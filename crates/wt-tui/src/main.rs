@@ -1,18 +1,33 @@
 //! wt-tui - Terminal UI for managing Claude Code sessions
 //!
-//! This provides a visual dashboard for:
-//! - Viewing all spawned workers and their status
-//! - Attaching to worker sessions
-//! - Reviewing diffs
-//! - Approving and merging workers
+//! Provides a visual dashboard for:
+//! - Viewing all spawned workers and their status, refreshed on a timer
+//! - Attaching to worker sessions, or opening one in a new terminal tab
+//! - Reviewing diffs and task notes
+//! - Merging and removing workers
+
+use std::io;
+use std::time::Duration;
 
 use anyhow::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
 
 mod app;
 mod ui;
 
+use app::{App, PendingConfirm};
+
+/// How long a single `event::poll` waits before giving `App::maybe_refresh`
+/// a chance to run, so the dashboard keeps moving even with no keypresses.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
 fn main() -> Result<()> {
-    // Initialize tracing
     tracing_subscriber::fmt()
         .with_env_filter(
             tracing_subscriber::EnvFilter::from_default_env()
@@ -20,13 +35,93 @@ fn main() -> Result<()> {
         )
         .init();
 
-    eprintln!("wt-tui is a placeholder. TUI implementation coming soon.");
-    eprintln!();
-    eprintln!("For now, use:");
-    eprintln!("  wt ps       - show worker status");
-    eprintln!("  wt attach   - attach to tmux session");
-    eprintln!("  wt review   - review a worker's diff");
-    eprintln!("  wt status   - show detailed worker info");
+    let mut terminal = enter_tui()?;
+    let mut app = App::new();
+
+    let result = run(&mut terminal, &mut app);
+
+    leave_tui(&mut terminal)?;
+    result
+}
+
+fn enter_tui() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    Ok(Terminal::new(CrosstermBackend::new(stdout))?)
+}
+
+fn leave_tui(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<()> {
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> Result<()> {
+    while !app.should_quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if event::poll(POLL_INTERVAL)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    handle_key(terminal, app, key.code)?;
+                }
+            }
+        }
+
+        app.maybe_refresh();
+    }
+
+    Ok(())
+}
+
+fn handle_key(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    code: KeyCode,
+) -> Result<()> {
+    // A pending confirm only ever looks at y/n (or Esc to cancel) so a
+    // destructive action never fires on the same keypress that requested it.
+    if let Some(pending) = app.pending_confirm {
+        match code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                app.pending_confirm = None;
+                match pending {
+                    PendingConfirm::Kill => app.kill_selected(),
+                    PendingConfirm::Remove => app.remove_selected(true),
+                }
+            }
+            _ => {
+                app.pending_confirm = None;
+                app.status_message = Some("Cancelled".to_string());
+            }
+        }
+        return Ok(());
+    }
+
+    match code {
+        KeyCode::Char('q') | KeyCode::Esc => app.should_quit = true,
+        KeyCode::Down | KeyCode::Char('j') => app.select_next(),
+        KeyCode::Up | KeyCode::Char('k') => app.select_prev(),
+        KeyCode::Char('a') => {
+            // `session::attach` execs into tmux on Unix, replacing this
+            // process outright; leave the alternate screen first so the
+            // handoff doesn't leave the terminal in raw/alt-screen mode.
+            leave_tui(terminal)?;
+            let outcome = app.attach_selected();
+            *terminal = enter_tui()?;
+            if let Err(e) = outcome {
+                app.status_message = Some(format!("Attach failed: {}", e));
+            }
+            app.refresh();
+        }
+        KeyCode::Char('m') => app.merge_selected(),
+        KeyCode::Char('o') => app.open_selected(),
+        KeyCode::Char('x') => app.pending_confirm = Some(PendingConfirm::Kill),
+        KeyCode::Char('r') => app.pending_confirm = Some(PendingConfirm::Remove),
+        _ => {}
+    }
 
     Ok(())
 }
@@ -1,10 +1,138 @@
 //! TUI rendering
 //!
-//! This will handle drawing the UI using ratatui:
-//! - Worker list with status indicators
-//! - Selected worker details
-//! - Diff preview
-//! - Help bar
-
-// Placeholder for TUI rendering
-// Will use ratatui for terminal rendering
+//! Layout: a worker list on the left, the selected worker's diff and
+//! `.wt/task.md` notes stacked on the right, and a help/status bar along
+//! the bottom.
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use wt_core::spawn::{TaskStatus, WindowRecency};
+
+use crate::app::App;
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let root = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(frame.area());
+
+    let body = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(root[0]);
+
+    draw_worker_list(frame, app, body[0]);
+    draw_details(frame, app, body[1]);
+    draw_help_bar(frame, app, root[1]);
+}
+
+fn status_style(status: TaskStatus) -> Style {
+    match status {
+        TaskStatus::Running => Style::default().fg(Color::Green),
+        TaskStatus::Exited => Style::default().fg(Color::Yellow),
+        TaskStatus::NoSession | TaskStatus::NoWindow => Style::default().fg(Color::DarkGray),
+    }
+}
+
+fn recency_marker(recency: WindowRecency) -> &'static str {
+    match recency {
+        WindowRecency::Current => "*",
+        WindowRecency::Previous => "-",
+        WindowRecency::Other => " ",
+    }
+}
+
+fn draw_worker_list(frame: &mut Frame, app: &App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .tasks
+        .iter()
+        .map(|task| {
+            let dirty = if task.status_summary == "clean" { " " } else { "●" };
+            let line = Line::from(vec![
+                Span::raw(format!("{} ", recency_marker(task.recency))),
+                Span::styled(dirty, Style::default().fg(Color::Red)),
+                Span::raw(format!(" {:<20}", task.name)),
+                Span::styled(
+                    format!("{:<10}", task.status.as_str()),
+                    status_style(task.status),
+                ),
+                Span::raw(task.branch.clone()),
+            ]);
+            ListItem::new(line)
+        })
+        .collect();
+
+    let title = format!(" Workers ({}) ", app.tasks.len());
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+    let mut state = ListState::default();
+    if !app.tasks.is_empty() {
+        state.select(Some(app.selected));
+    }
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_details(frame: &mut Frame, app: &App, area: Rect) {
+    let panes = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
+        .split(area);
+
+    let title = match app.selected_task() {
+        Some(task) => format!(" {} -> {} ", task.branch, app.base_branch),
+        None => " Diff ".to_string(),
+    };
+    let diff_lines: Vec<Line> = app.diff.lines().map(diff_line).collect();
+    let diff = Paragraph::new(diff_lines)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(diff, panes[0]);
+
+    let notes = Paragraph::new(app.task_notes.as_str())
+        .block(Block::default().borders(Borders::ALL).title(" task.md "))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(notes, panes[1]);
+}
+
+fn diff_line(line: &str) -> Line<'static> {
+    let style = if line.starts_with('+') && !line.starts_with("+++") {
+        Style::default().fg(Color::Green)
+    } else if line.starts_with('-') && !line.starts_with("---") {
+        Style::default().fg(Color::Red)
+    } else if line.starts_with("@@") {
+        Style::default().fg(Color::Cyan)
+    } else {
+        Style::default()
+    };
+    Line::from(Span::styled(line.to_string(), style))
+}
+
+fn draw_help_bar(frame: &mut Frame, app: &App, area: Rect) {
+    let text = if let Some(message) = &app.status_message {
+        Line::from(Span::raw(message.clone()))
+    } else {
+        Line::from(vec![
+            Span::styled(" ↑/k ↓/j ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("move  "),
+            Span::styled("a", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("ttach  "),
+            Span::styled("o", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("pen tab  "),
+            Span::styled("x", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" kill  "),
+            Span::styled("m", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("erge  "),
+            Span::styled("r", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("emove  "),
+            Span::styled("q", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("uit"),
+        ])
+    };
+    frame.render_widget(Paragraph::new(text), area);
+}